@@ -0,0 +1,155 @@
+use {
+    quote::{quote, ToTokens},
+    syn::{
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Error, Expr, ExprLit, Ident, Lit, LitInt, Token,
+    },
+};
+
+/// Arguments of `#[account(...)]`.
+#[derive(Default)]
+pub struct AccountArgs {
+    pub owner: Option<Expr>,
+    pub discriminator: Option<Expr>,
+    pub foreign: bool,
+    pub anchor: bool,
+    pub singleton: bool,
+    pub version: Option<u8>,
+}
+
+impl AccountArgs {
+    /// An account is foreign when it is not owned by the current program.
+    pub fn is_foreign(&self) -> bool {
+        self.foreign || self.owner.is_some()
+    }
+
+    /// The tokens of the owner, decoding it at compile time when given as a base58 string.
+    pub fn owner_tokens(&self) -> syn::Result<Option<proc_macro2::TokenStream>> {
+        let Some(ref owner) = self.owner else {
+            return Ok(None);
+        };
+
+        let tokens = match owner {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => {
+                let bytes = decode_pubkey(&lit.value())
+                    .ok_or_else(|| Error::new(lit.span(), "Invalid base58 public key"))?;
+                quote!(program::pubkey_from_array([#(#bytes),*]))
+            }
+            expr => expr.to_token_stream(),
+        };
+
+        Ok(Some(tokens))
+    }
+}
+
+impl Parse for AccountArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = AccountArgs::default();
+
+        for arg in Punctuated::<AccountArg, Token![,]>::parse_terminated(input)? {
+            match arg {
+                AccountArg::Owner(expr) => args.owner = Some(expr),
+                AccountArg::Discriminator(expr) => args.discriminator = Some(expr),
+                AccountArg::Foreign => args.foreign = true,
+                AccountArg::Anchor => args.anchor = true,
+                AccountArg::Singleton => args.singleton = true,
+                AccountArg::Version(version) => args.version = Some(version),
+            }
+        }
+
+        if args.anchor && args.discriminator.is_some() {
+            return Err(Error::new(
+                input.span(),
+                "`anchor` and `discriminator` cannot be used together",
+            ));
+        }
+
+        if args.discriminator.is_some() && !args.is_foreign() && args.version.is_none() {
+            return Err(Error::new(
+                input.span(),
+                "A discriminator can only be set on `foreign` accounts, with an `owner` or a `version`",
+            ));
+        }
+
+        if args.anchor && !args.is_foreign() {
+            return Err(Error::new(
+                input.span(),
+                "`anchor` can only be set on `foreign` accounts or with an `owner`",
+            ));
+        }
+
+        if args.version.is_some() && !args.anchor && args.discriminator.is_none() {
+            return Err(Error::new(
+                input.span(),
+                "`version` requires a `discriminator`, shared by all the versions of the account",
+            ));
+        }
+
+        Ok(args)
+    }
+}
+
+enum AccountArg {
+    Owner(Expr),
+    Discriminator(Expr),
+    Foreign,
+    Anchor,
+    Singleton,
+    Version(u8),
+}
+
+impl Parse for AccountArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        match name.to_string().as_str() {
+            "owner" => {
+                input.parse::<Token![=]>()?;
+                Ok(AccountArg::Owner(input.parse()?))
+            }
+            "discriminator" => {
+                input.parse::<Token![=]>()?;
+                Ok(AccountArg::Discriminator(input.parse()?))
+            }
+            "foreign" => Ok(AccountArg::Foreign),
+            "anchor" => Ok(AccountArg::Anchor),
+            "singleton" => Ok(AccountArg::Singleton),
+            "version" => {
+                input.parse::<Token![=]>()?;
+                Ok(AccountArg::Version(
+                    input.parse::<LitInt>()?.base10_parse()?,
+                ))
+            }
+            _ => Err(Error::new(name.span(), "Unknown account argument")),
+        }
+    }
+}
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn decode_pubkey(value: &str) -> Option<[u8; 32]> {
+    // Little endian big number, the leading '1's are the leading zero bytes
+    let mut bytes: Vec<u8> = Vec::with_capacity(32);
+
+    for c in value.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let zeros = value.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat(0).take(zeros));
+    bytes.reverse();
+
+    bytes.try_into().ok()
+}
@@ -0,0 +1,288 @@
+use {
+    heck::{ToShoutySnakeCase, ToSnakeCase},
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    syn::{
+        punctuated::Punctuated, spanned::Spanned, Attribute, Error, Fields, Ident, ItemEnum, Path,
+        Token, Type,
+    },
+};
+
+/// Traits derived on the storage, which the enum can't derive again.
+const STORAGE_DERIVES: [&str; 4] = ["Pod", "Zeroable", "Clone", "Copy"];
+
+/// Attributes of the enum kept on the storage, which has its own `repr`.
+fn storage_attrs(attrs: &[Attribute]) -> syn::Result<Vec<&Attribute>> {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        let paths = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        if let Some(path) = paths.iter().find(|path| {
+            path.segments
+                .last()
+                .is_some_and(|seg| STORAGE_DERIVES.iter().any(|derive| seg.ident == derive))
+        }) {
+            return Err(Error::new(
+                path.span(),
+                "Enum accounts already derive `Pod`, `Zeroable`, `Clone` and `Copy` on their storage",
+            ));
+        }
+    }
+
+    Ok(attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("repr"))
+        .collect())
+}
+
+/// A variant of an enum account and the type of its payload.
+struct Variant {
+    ident: Ident,
+    payload: Option<Type>,
+    attrs: Vec<Attribute>,
+}
+
+/// Expands an enum into a tagged union readable without copy.
+///
+/// The data is laid out as the tag byte, a padding aligning the payload and a payload as large as
+/// the largest variant, rounded to the alignment. The storage is aligned like the payloads so
+/// the views borrow them in place. The enum name is given to the storage and the variants are
+/// accessed with the generated `<Name>Ref` and `<Name>Mut` views. Named fields variants get a
+/// `<Name><Variant>` struct holding them.
+pub fn expand_enum(item: &ItemEnum) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "Generic enum accounts are not supported",
+        ));
+    }
+
+    if item.variants.len() > u8::MAX as usize + 1 {
+        return Err(Error::new(item.span(), "Too many variants for a tag byte"));
+    }
+
+    let name = &item.ident;
+    let vis = &item.vis;
+    let attrs = storage_attrs(&item.attrs)?;
+    let ref_name = format_ident!("{name}Ref");
+    let mut_name = format_ident!("{name}Mut");
+    let prefix = name.to_string().to_shouty_snake_case();
+    let padding_len = format_ident!("__{prefix}_PADDING_LEN");
+    let payload_len = format_ident!("__{prefix}_PAYLOAD_LEN");
+
+    let mut payload_structs = Vec::new();
+    let mut variants = Vec::with_capacity(item.variants.len());
+    for variant in &item.variants {
+        if let Some((_, ref discriminant)) = variant.discriminant {
+            return Err(Error::new(
+                discriminant.span(),
+                "Explicit discriminants are not supported, the tag is the variant index",
+            ));
+        }
+
+        let ident = &variant.ident;
+        let payload = match variant.fields {
+            Fields::Unit => None,
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                Some(fields.unnamed[0].ty.clone())
+            }
+            Fields::Unnamed(ref fields) => {
+                return Err(Error::new(
+                    fields.span(),
+                    "Tuple variants must have a single field, use named fields instead",
+                ))
+            }
+            Fields::Named(ref fields) => {
+                let struct_name = format_ident!("{name}{ident}");
+                let fields = fields.named.iter().map(|field| {
+                    let field_attrs = &field.attrs;
+                    let field_name = &field.ident;
+                    let field_ty = &field.ty;
+                    quote!(#(#field_attrs)* pub #field_name: #field_ty)
+                });
+                payload_structs.push(quote! {
+                    #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+                    #[repr(C)]
+                    #vis struct #struct_name {
+                        #(#fields),*
+                    }
+                });
+                Some(syn::parse_quote!(#struct_name))
+            }
+        };
+
+        variants.push(Variant {
+            ident: ident.clone(),
+            payload,
+            attrs: variant.attrs.clone(),
+        });
+    }
+
+    let payload_types: Vec<&Type> = variants.iter().filter_map(|v| v.payload.as_ref()).collect();
+    // Empty arrays aligning the storage like its payloads
+    let align_fields = payload_types.iter().enumerate().map(|(i, ty)| {
+        let field = format_ident!("_align_{i}");
+        quote!(#field: [#ty; 0])
+    });
+    // Views of enums without payload don't borrow anything
+    let (lifetime, elided) = if payload_types.is_empty() {
+        (quote!(), quote!())
+    } else {
+        (quote!(<'a>), quote!(<'_>))
+    };
+
+    let ref_variants = variants.iter().map(|v| {
+        let Variant {
+            ident,
+            payload,
+            attrs,
+        } = v;
+        match payload {
+            Some(ty) => quote!(#(#attrs)* #ident(&'a #ty)),
+            None => quote!(#(#attrs)* #ident),
+        }
+    });
+    let mut_variants = variants.iter().map(|v| {
+        let Variant {
+            ident,
+            payload,
+            attrs,
+        } = v;
+        match payload {
+            Some(ty) => quote!(#(#attrs)* #ident(&'a mut #ty)),
+            None => quote!(#(#attrs)* #ident),
+        }
+    });
+
+    let ref_arms = variants.iter().enumerate().map(|(i, v)| {
+        let tag = i as u8;
+        let ident = &v.ident;
+        match v.payload {
+            Some(ref ty) => quote! {
+                #tag => program::bytes::try_from_bytes::<#ty>(
+                    &self.payload[..core::mem::size_of::<#ty>()],
+                )
+                .map(#ref_name::#ident),
+            },
+            None => quote!(#tag => Some(#ref_name::#ident),),
+        }
+    });
+    let mut_arms = variants.iter().enumerate().map(|(i, v)| {
+        let tag = i as u8;
+        let ident = &v.ident;
+        match v.payload {
+            Some(ref ty) => quote! {
+                #tag => program::bytes::try_from_bytes_mut::<#ty>(
+                    &mut self.payload[..core::mem::size_of::<#ty>()],
+                )
+                .map(#mut_name::#ident),
+            },
+            None => quote!(#tag => Some(#mut_name::#ident),),
+        }
+    });
+
+    let setters = variants.iter().enumerate().map(|(i, v)| {
+        let tag = i as u8;
+        let ident = &v.ident;
+        let setter = format_ident!("set_{}", ident.to_string().to_snake_case());
+        let doc = format!(" Switches to `{ident}`, the previous payload is cleared.");
+        match v.payload {
+            Some(ref ty) => {
+                quote! {
+                    #[doc = #doc]
+                    pub fn #setter(&mut self, value: #ty) {
+                        let bytes = bytemuck::bytes_of(&value);
+                        self.tag = #tag;
+                        self.payload.fill(0);
+                        self.payload[..bytes.len()].copy_from_slice(bytes);
+                    }
+                }
+            }
+            None => {
+                quote! {
+                    #[doc = #doc]
+                    pub fn #setter(&mut self) {
+                        self.tag = #tag;
+                        self.payload.fill(0);
+                    }
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#payload_structs)*
+
+        #[doc(hidden)]
+        const #padding_len: usize = {
+            let aligns = [1 #(, core::mem::align_of::<#payload_types>())*];
+            let mut max = 0;
+            let mut i = 0;
+            while i < aligns.len() {
+                if aligns[i] > max {
+                    max = aligns[i];
+                }
+                i += 1;
+            }
+            max - 1
+        };
+
+        #[doc(hidden)]
+        const #payload_len: usize = {
+            let sizes = [0 #(, core::mem::size_of::<#payload_types>())*];
+            let mut max: usize = 0;
+            let mut i = 0;
+            while i < sizes.len() {
+                if sizes[i] > max {
+                    max = sizes[i];
+                }
+                i += 1;
+            }
+            max.next_multiple_of(#padding_len + 1)
+        };
+
+        #(#attrs)*
+        #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+        #[repr(C)]
+        #vis struct #name {
+            #(#align_fields,)*
+            tag: u8,
+            _padding: [u8; #padding_len],
+            payload: [u8; #payload_len],
+        }
+
+        #[derive(Clone, Copy)]
+        #vis enum #ref_name #lifetime {
+            #(#ref_variants),*
+        }
+
+        #vis enum #mut_name #lifetime {
+            #(#mut_variants),*
+        }
+
+        impl #name {
+            /// Index of the current variant.
+            pub fn tag(&self) -> u8 {
+                self.tag
+            }
+
+            /// Borrows the current variant.
+            pub fn view(&self) -> Result<#ref_name #elided, program::program_error::ProgramError> {
+                match self.tag {
+                    #(#ref_arms)*
+                    _ => None,
+                }
+                .ok_or(program::program_error::ProgramError::InvalidAccountData)
+            }
+
+            /// Mutably borrows the current variant.
+            pub fn view_mut(&mut self) -> Result<#mut_name #elided, program::program_error::ProgramError> {
+                match self.tag {
+                    #(#mut_arms)*
+                    _ => None,
+                }
+                .ok_or(program::program_error::ProgramError::InvalidAccountData)
+            }
+
+            #(#setters)*
+        }
+    })
+}
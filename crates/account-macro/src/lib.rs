@@ -1,11 +1,65 @@
+mod args;
+mod enums;
+mod tail;
+
 use {
+    args::AccountArgs,
+    enums::expand_enum,
     proc_macro::TokenStream,
     quote::{quote, ToTokens},
-    syn::{parse_macro_input, spanned::Spanned, Error, Item},
+    sha2::{Digest, Sha256},
+    syn::{
+        parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Expr, ExprLit, Index, Item,
+        Lit, LitByteStr,
+    },
+    tail::{expand_tail, find_tail},
 };
 
+/// Declares the layout of an account.
+///
+/// By default the account is owned by `crate::ID`, which makes it `ProgramOwned`, and has no
+/// discriminator. The layout of an account of another program is described with:
+/// * `owner = "<base58>"` or `owner = path::ID`: the program owning the account.
+/// * `foreign`: the account is not owned by this program, `Owner` is left to implement when no
+///   `owner` is given.
+/// * `discriminator = [...]` or `b"..."`: the bytes the data starts with, checked when loading
+///   the account.
+/// * `anchor`: the 8 bytes discriminator of Anchor, `sha256("account:<Name>")[..8]`.
+///
+/// The data is read without copy after the discriminator, so the length of the discriminator must
+/// be a multiple of the alignment of the account. A foreign layout with another discriminator
+/// is declared with the integers of `typhoon-pod`, aligned on 1 byte.
+///
+/// `version = N` stores the version of the layout in a byte following the discriminator, padded
+/// with zeros to the alignment, so an account written with another version fails the
+/// discriminator check. It requires a discriminator, also allowed on the accounts of this
+/// program, identifying the account whatever its version: the version byte alone would match any
+/// account starting with it. Older versions are converted with `Migrate` and
+/// `#[constraint(migrate = OldLayout, payer = ...)]`.
+///
+/// `singleton` stores the account at a single address, derived from the name of the type, and
+/// loads it with `Singleton<T>`.
+///
+/// A struct can end with a `#[tail]` slice, `[Entry]`, holding as many entries as the account
+/// length allows. `data()` then borrows the fields and the entries, which are added and removed
+/// with `TailAccount`.
+///
+/// Enums are stored as a tagged union: the variant index, a padding and the largest payload.
+/// Unlike the other accounts, `data()` doesn't give the declared type: the enum name is given to
+/// the storage, a struct, and the variants are borrowed with `data()?.view()?` as a
+/// `<Name>Ref`. `mut_data()` gives `view_mut()` and the `set_<variant>` methods switching
+/// variants. The `repr` of the enum is dropped, the storage being `#[repr(C)]`, and its other
+/// attributes are kept.
+///
+/// ```ignore
+/// #[account(owner = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8", anchor)]
+/// pub struct Pool {
+///     pub reserve: u64,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn account(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn account(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as AccountArgs);
     let item = parse_macro_input!(item as Item);
     let (name, generics) = match item {
         Item::Struct(ref item_struct) => (&item_struct.ident, &item_struct.generics),
@@ -18,17 +72,176 @@ pub fn account(_attr: TokenStream, item: TokenStream) -> TokenStream {
     };
     let (_, ty_generics, where_clause) = generics.split_for_impl();
 
-    quote! {
-        #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
-        #[repr(C)]
-        #item
+    // Alignment of the data, a tail being aligned like its header and entries
+    let (layout, align) = match item {
+        Item::Enum(ref item_enum) => match expand_enum(item_enum) {
+            Ok(layout) => (layout, quote!(core::mem::align_of::<#name>())),
+            Err(err) => return err.into_compile_error().into(),
+        },
+        Item::Struct(ref item_struct) => match find_tail(item_struct) {
+            Ok(Some(position)) => match expand_tail(item_struct, position) {
+                Ok(layout_and_align) => layout_and_align,
+                Err(err) => return err.into_compile_error().into(),
+            },
+            Ok(None) => (
+                quote! {
+                    #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+                    #[repr(C)]
+                    #item
+                },
+                quote!(core::mem::align_of::<#name #ty_generics>()),
+            ),
+            Err(err) => return err.into_compile_error().into(),
+        },
+        _ => unreachable!(),
+    };
 
-        impl Owner for #name #ty_generics #where_clause {
-            const OWNER: program::pubkey::Pubkey = crate::ID;
+    let owner = match args.owner_tokens() {
+        Ok(Some(owner)) => Some(owner),
+        Ok(None) if args.is_foreign() => None,
+        Ok(None) => Some(quote!(crate::ID)),
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let owner_impl = owner.map(|owner| {
+        quote! {
+            impl Owner for #name #ty_generics #where_clause {
+                const OWNER: program::pubkey::Pubkey = #owner;
+            }
+        }
+    });
+    let program_owned_impl = (!args.is_foreign()).then(|| {
+        quote! {
+            impl ProgramOwned for #name #ty_generics #where_clause {}
         }
+    });
+
+    let discriminator = if args.anchor {
+        // Same derivation as Anchor to read the accounts of its programs
+        let hash = Sha256::digest(format!("account:{name}"));
+        let discriminator = &hash[..8];
+        quote!(&[#(#discriminator),*])
+    } else if let Some(ref discriminator) = args.discriminator {
+        match discriminator {
+            Expr::Lit(ExprLit {
+                lit: Lit::ByteStr(lit),
+                ..
+            }) => quote!(#lit),
+            expr => quote!(&#expr),
+        }
+    } else {
+        quote!(&[])
+    };
+    let discriminator = match args.version {
+        Some(version) => quote! {
+            {
+                const PREFIX: &[u8] = #discriminator;
+                // Zeros follow the version byte up to the alignment of the data
+                const LEN: usize = (PREFIX.len() + 1).next_multiple_of(#align);
+                const BYTES: [u8; LEN] = {
+                    let mut bytes = [0; LEN];
+                    let mut i = 0;
+                    while i < PREFIX.len() {
+                        bytes[i] = PREFIX[i];
+                        i += 1;
+                    }
+                    bytes[PREFIX.len()] = #version;
+                    bytes
+                };
+                &BYTES
+            }
+        },
+        None => discriminator,
+    };
+    let singleton_impl = args.singleton.then(|| {
+        let seed = LitByteStr::new(name.to_string().as_bytes(), name.span());
+        quote! {
+            impl SingletonData for #name #ty_generics #where_clause {
+                const SEED: &'static [u8] = #seed;
+            }
+        }
+    });
+    let version_impl = args.version.map(|version| {
+        quote! {
+            impl Versioned for #name #ty_generics #where_clause {
+                const VERSION: u8 = #version;
+            }
+        }
+    });
+
+    quote! {
+        #layout
+
+        #owner_impl
+
+        #program_owned_impl
+
+        #singleton_impl
+
+        #version_impl
 
         impl Discriminator for #name #ty_generics #where_clause {
-            const DISCRIMINATOR: &'static [u8] = &[];
+            const DISCRIMINATOR: &'static [u8] = #discriminator;
+        }
+
+        const _: () = assert!(
+            <#name #ty_generics as Discriminator>::DISCRIMINATOR.len() % #align == 0,
+            "The length of the discriminator must be a multiple of the alignment of the account",
+        );
+    }
+    .into_token_stream()
+    .into()
+}
+
+/// Implements `AccountWrapper` for a struct whose first field is the wrapped account, making it
+/// usable in a context like the account itself. The checks of the wrapper are implemented with
+/// `FromAccountInfo`.
+///
+/// `#[context]` injects its lifetime in the wrapper and in the accounts it takes, like
+/// `Admin<Signer>`. A wrapper taking a data type, a program or a sysvar is written with the
+/// lifetime instead, like `Filled<'info, Data>`, and is left as is.
+///
+/// ```ignore
+/// #[derive(AccountWrapper)]
+/// pub struct Admin<'a, T: ReadableAccount>(Signer<'a, T>);
+/// ```
+#[proc_macro_derive(AccountWrapper)]
+pub fn derive_account_wrapper(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let Data::Struct(ref data) = input.data else {
+        return Error::new(
+            input.span(),
+            "AccountWrapper can only be derived for structs",
+        )
+        .into_compile_error()
+        .into();
+    };
+    let Some(field) = data.fields.iter().next() else {
+        return Error::new(input.span(), "The first field must be the wrapped account")
+            .into_compile_error()
+            .into();
+    };
+    let field_ty = &field.ty;
+    let field_name = match field.ident {
+        Some(ref ident) => ident.to_token_stream(),
+        None => Index::from(0).to_token_stream(),
+    };
+
+    quote! {
+        impl #impl_generics AsRef<program::RawAccountInfo> for #name #ty_generics #where_clause {
+            fn as_ref(&self) -> &program::RawAccountInfo {
+                AsRef::<program::RawAccountInfo>::as_ref(&self.#field_name)
+            }
+        }
+
+        impl #impl_generics AccountWrapper for #name #ty_generics #where_clause {
+            type Inner = #field_ty;
+
+            fn inner(&self) -> &Self::Inner {
+                &self.#field_name
+            }
         }
     }
     .into_token_stream()
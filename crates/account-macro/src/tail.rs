@@ -0,0 +1,127 @@
+use {
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote},
+    syn::{spanned::Spanned, Error, Fields, ItemStruct, Type},
+};
+
+/// Returns the position of the `#[tail]` field, which must be the last one.
+pub fn find_tail(item: &ItemStruct) -> syn::Result<Option<usize>> {
+    let Fields::Named(ref fields) = item.fields else {
+        return Ok(None);
+    };
+
+    let Some(position) = fields
+        .named
+        .iter()
+        .position(|field| field.attrs.iter().any(|attr| attr.path().is_ident("tail")))
+    else {
+        return Ok(None);
+    };
+
+    if position != fields.named.len() - 1 {
+        return Err(Error::new(
+            fields.named[position].span(),
+            "The `#[tail]` field must be the last one",
+        ));
+    }
+
+    Ok(Some(position))
+}
+
+/// Expands a struct ending with a `#[tail]` slice into a dynamically sized type.
+///
+/// The other fields are copied in a `<Name>Header` struct giving the fixed part of the layout and
+/// the number of entries is deduced from the account length. To keep the layout without padding,
+/// the header length must be a multiple of the entry alignment and the entry length a multiple
+/// of the header alignment.
+///
+/// Returns the layout and the alignment of the struct, the largest of the header and the entry.
+pub fn expand_tail(item: &ItemStruct, position: usize) -> syn::Result<(TokenStream, TokenStream)> {
+    if !item.generics.params.is_empty() {
+        return Err(Error::new(
+            item.generics.span(),
+            "Generic accounts with a tail are not supported",
+        ));
+    }
+
+    let name = &item.ident;
+    let vis = &item.vis;
+    let attrs = &item.attrs;
+    let header_name = format_ident!("{name}Header");
+
+    let mut fields: Vec<_> = item.fields.iter().cloned().collect();
+    let mut tail = fields.remove(position);
+    tail.attrs.retain(|attr| !attr.path().is_ident("tail"));
+
+    let Type::Slice(ref slice) = tail.ty else {
+        return Err(Error::new(
+            tail.ty.span(),
+            "The `#[tail]` field must be a slice like `[Entry]`",
+        ));
+    };
+    let entry = &slice.elem;
+    let tail_name = &tail.ident;
+
+    let align = quote! {
+        {
+            let header = core::mem::align_of::<#header_name>();
+            let entry = core::mem::align_of::<#entry>();
+            if header > entry { header } else { entry }
+        }
+    };
+
+    let layout = quote! {
+        #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+        #[repr(C)]
+        #vis struct #header_name {
+            #(#fields),*
+        }
+
+        #(#attrs)*
+        #[repr(C)]
+        #vis struct #name {
+            #(#fields,)*
+            #tail
+        }
+
+        const _: () = assert!(
+            core::mem::size_of::<#entry>() > 0
+                && core::mem::size_of::<#header_name>() % core::mem::align_of::<#entry>() == 0
+                && core::mem::size_of::<#entry>() % core::mem::align_of::<#header_name>() == 0,
+            "The tail entries must not need padding after the header or between them"
+        );
+
+        impl AccountData for #name {
+            const MIN_LEN: usize = core::mem::size_of::<#header_name>();
+
+            fn read(data: &[u8]) -> Option<&Self> {
+                read_tail(data)
+            }
+
+            fn read_mut(data: &mut [u8]) -> Option<&mut Self> {
+                read_tail_mut(data)
+            }
+        }
+
+        // SAFETY: the struct is `#[repr(C)]`, the header fields followed by the entries, and the
+        // assertion above rules out any padding.
+        unsafe impl Tail for #name {
+            type Header = #header_name;
+            type Entry = #entry;
+
+            fn tail(&self) -> &[Self::Entry] {
+                &self.#tail_name
+            }
+
+            fn tail_mut(&mut self) -> &mut [Self::Entry] {
+                &mut self.#tail_name
+            }
+
+            fn from_raw_parts(data: *const u8, len: usize) -> *const Self {
+                core::ptr::slice_from_raw_parts(data, len) as *const Self
+            }
+        }
+    };
+
+    Ok((layout, align))
+}
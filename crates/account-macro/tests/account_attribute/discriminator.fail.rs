@@ -0,0 +1,33 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[account(discriminator = [1])]
+pub struct Local {
+    pub foo: u64,
+}
+
+#[account(anchor)]
+pub struct LocalAnchor {
+    pub foo: u64,
+}
+
+#[account(version = 1)]
+pub struct Unidentified {
+    pub foo: u64,
+}
+
+#[account(owner = ID, discriminator = [1, 2])]
+pub struct Misaligned {
+    pub foo: u64,
+}
+
+#[account(owner = "not a pubkey")]
+pub struct Invalid {
+    pub foo: u64,
+}
+
+pub fn main() {}
@@ -0,0 +1,89 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+#[repr(C)]
+pub struct Lock {
+    pub until: i64,
+    pub authority: Pubkey,
+}
+
+#[account]
+pub enum Vault {
+    Closed,
+    Open {
+        amount: u64,
+        bump: u8,
+        _padding: [u8; 7],
+    },
+    Locked(Lock),
+}
+
+#[account]
+pub enum Slot {
+    Counter(u64),
+    Name([u8; 9]),
+}
+
+/// The `repr` is dropped, the other attributes are kept on the storage
+#[account]
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Paused,
+}
+
+fn assert_readable<'a, T: FromAccountInfo<'a> + ReadableAccount<DataType = U>, U>() {}
+
+pub fn main() {
+    assert_readable::<Account<Vault>, Vault>();
+    assert_eq!(
+        core::mem::size_of::<Vault>(),
+        8 + core::mem::size_of::<Lock>()
+    );
+    assert_eq!(core::mem::align_of::<Vault>(), 8);
+    assert_eq!(core::mem::size_of::<Slot>(), 8 + 16);
+    assert_eq!(core::mem::size_of::<Status>(), 1);
+
+    let mut vault: Vault = bytemuck::Zeroable::zeroed();
+
+    assert!(matches!(vault.view(), Ok(VaultRef::Closed)));
+
+    vault.set_open(VaultOpen {
+        amount: 10,
+        bump: 255,
+        _padding: [0; 7],
+    });
+    assert_eq!(vault.tag(), 1);
+    if let Ok(VaultMut::Open(open)) = vault.view_mut() {
+        open.amount += 5;
+    }
+    assert!(
+        matches!(vault.view(), Ok(VaultRef::Open(open)) if open.amount == 15 && open.bump == 255)
+    );
+
+    vault.set_locked(Lock {
+        until: 100,
+        authority: ID,
+    });
+    assert!(
+        matches!(vault.view(), Ok(VaultRef::Locked(lock)) if lock.until == 100 && lock.authority == ID)
+    );
+
+    vault.set_closed();
+    assert!(matches!(vault.view(), Ok(VaultRef::Closed)));
+
+    let mut slot: Slot = bytemuck::Zeroable::zeroed();
+    slot.set_name(*b"long name");
+    assert!(matches!(slot.view(), Ok(SlotRef::Name(name)) if name == b"long name"));
+
+    let mut status: Status = bytemuck::Zeroable::zeroed();
+    status.set_paused();
+    assert!(matches!(status.view(), Ok(StatusRef::Paused)));
+    assert_ne!(status, bytemuck::Zeroable::zeroed());
+}
@@ -1,5 +1,4 @@
-use typhoon::prelude::*;
-use typhoon::program::pubkey::Pubkey;
+use typhoon::{prelude::*, program::pubkey::Pubkey};
 
 pub const ID: Pubkey = [
     218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
@@ -7,9 +6,10 @@ pub const ID: Pubkey = [
 ];
 
 #[account]
-pub enum TestState {
-    One,
-    Two,
+#[derive(Debug, Clone, Copy)]
+pub enum Status {
+    Active,
+    Paused,
 }
 
 pub fn main() {}
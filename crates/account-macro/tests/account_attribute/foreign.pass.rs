@@ -0,0 +1,49 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+pub const AMM_ID: Pubkey = [
+    75, 217, 73, 196, 54, 2, 195, 63, 32, 119, 144, 237, 22, 163, 82, 76, 161, 185, 151, 92, 241,
+    33, 162, 169, 12, 255, 236, 125, 248, 182, 138, 205,
+];
+
+#[account(owner = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8")]
+pub struct Feed {
+    pub price: u64,
+}
+
+#[account(owner = AMM_ID, discriminator = [1, 2])]
+pub struct Config {
+    pub fee: PodU64,
+}
+
+#[account(owner = AMM_ID, anchor)]
+pub struct Pool {
+    pub reserve: u64,
+}
+
+#[account(foreign, discriminator = [7])]
+pub struct Position {
+    pub amount: PodU64,
+}
+
+impl Owner for Position {
+    const OWNER: Pubkey = AMM_ID;
+}
+
+fn assert_readable<'a, T: FromAccountInfo<'a> + ReadableAccount>() {}
+
+pub fn main() {
+    assert_eq!(Feed::OWNER, AMM_ID);
+    assert_eq!(Feed::DISCRIMINATOR, &[] as &[u8]);
+    assert_eq!(Config::DISCRIMINATOR, &[1, 2]);
+    assert_eq!(Pool::DISCRIMINATOR, &[241, 154, 109, 4, 17, 177, 109, 188]);
+    assert_eq!(Position::OWNER, AMM_ID);
+
+    assert_readable::<Account<Feed>>();
+    assert_readable::<Account<Position>>();
+    assert_readable::<AnchorAccount<Pool>>();
+}
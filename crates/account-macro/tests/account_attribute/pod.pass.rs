@@ -0,0 +1,52 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[account]
+pub struct Profile {
+    pub active: PodBool,
+    pub score: PodU64,
+    pub balance: PodI64,
+    pub level: PodOption<PodU16>,
+    pub delegate: PodPubkeyOption,
+    pub name: FixedString<16>,
+    pub friends: FixedVec<Pubkey, 4>,
+}
+
+pub fn main() {
+    let mut profile: Profile = bytemuck::Zeroable::zeroed();
+    assert!(!profile.active.get());
+    assert_eq!(profile.level.get(), None);
+    assert_eq!(profile.delegate.get(), None);
+    assert!(profile.name.is_empty());
+    assert!(profile.friends.is_empty());
+
+    profile.active = true.into();
+    profile.score = PodU64::new(u64::MAX);
+    profile.balance = (-5).into();
+    profile.level.set(Some(PodU16::new(3)));
+    profile.delegate = PodPubkeyOption::some(ID).unwrap();
+    profile.name.set("typhoon").unwrap();
+    profile.friends.push(ID).unwrap();
+
+    assert!(profile.active.get());
+    assert_eq!(profile.score.get(), u64::MAX);
+    assert_eq!(i64::from(profile.balance), -5);
+    assert_eq!(profile.level.get().map(PodU16::get), Some(3));
+    assert_eq!(profile.delegate.get(), Some(&ID));
+    assert_eq!(profile.name.as_str().unwrap(), "typhoon");
+    assert_eq!(profile.friends.as_slice(), &[ID]);
+
+    assert!(PodPubkeyOption::some([0; 32]).is_err());
+    assert!(FixedString::<4>::new("too long").is_err());
+
+    let mut vec = FixedVec::<u8, 2>::new();
+    vec.push(1).unwrap();
+    vec.push(2).unwrap();
+    assert!(vec.push(3).is_err());
+    assert_eq!(vec.remove(0), Some(1));
+    assert_eq!(&*vec, &[2]);
+}
@@ -0,0 +1,12 @@
+use typhoon::prelude::*;
+
+#[account(owner = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8")]
+pub struct Feed {
+    pub price: u64,
+}
+
+fn assert_lamports<T: Lamports>() {}
+
+pub fn main() {
+    assert_lamports::<Mut<Account<Feed>>>();
+}
@@ -0,0 +1,42 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[account(singleton)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee: u64,
+}
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = core::mem::size_of::<Config>()
+    )]
+    pub config: Mut<Singleton<Config>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct ReadContext {
+    pub config: Singleton<Config>,
+}
+
+fn assert_readable<'a, T: FromAccountInfo<'a> + ReadableAccount>() {}
+fn assert_writable<'a, T: FromAccountInfo<'a> + WritableAccount + Lamports>() {}
+
+pub fn main() {
+    assert_eq!(Config::SEED, b"Config");
+
+    assert_readable::<Singleton<Config>>();
+    assert_writable::<Mut<Singleton<Config>>>();
+}
@@ -0,0 +1,58 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy, PartialEq, Debug)]
+#[repr(C)]
+pub struct Position {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub total: u64,
+    #[tail]
+    pub positions: [Position],
+}
+
+fn assert_tail_account<T: TailAccount<Pool>>() {}
+
+pub fn main() {
+    assert_tail_account::<Mut<Account<Pool>>>();
+    assert_eq!(Pool::MIN_LEN, 40);
+    assert_eq!(Pool::space(2), 40 + 2 * 40);
+
+    #[repr(C, align(8))]
+    struct Aligned([u8; 120]);
+    let mut data = Aligned([0; 120]);
+
+    let pool = <Pool as AccountData>::read_mut(&mut data.0).unwrap();
+    assert_eq!(pool.positions.len(), 2);
+    pool.total = 5;
+    pool.tail_mut()[1] = Position {
+        owner: ID,
+        amount: 5,
+    };
+
+    let pool = Pool::read(&data.0[..80]).unwrap();
+    assert_eq!(pool.total, 5);
+    assert_eq!(pool.tail().len(), 1);
+    assert_eq!(
+        Pool::read(&data.0).unwrap().positions[1],
+        Position {
+            owner: ID,
+            amount: 5,
+        }
+    );
+
+    assert!(Pool::read(&data.0[..39]).is_none());
+    assert!(Pool::read(&data.0[..50]).is_none());
+
+    // Misaligned data
+    assert!(Pool::read(&data.0[1..81]).is_none());
+}
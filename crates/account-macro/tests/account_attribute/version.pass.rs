@@ -0,0 +1,68 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[account(discriminator = b"config", version = 1)]
+pub struct ConfigV1 {
+    pub fee: u64,
+}
+
+#[account(discriminator = b"config", version = 2)]
+pub struct Config {
+    pub fee: u64,
+    pub max_fee: u64,
+}
+
+impl Migrate<ConfigV1> for Config {
+    fn migrate(from: &ConfigV1) -> Self {
+        Config {
+            fee: from.fee,
+            max_fee: u64::MAX,
+        }
+    }
+}
+
+#[account(foreign, discriminator = [1, 2], version = 3)]
+pub struct Position {
+    pub amount: u64,
+}
+
+impl Owner for Position {
+    const OWNER: Pubkey = ID;
+}
+
+#[account(owner = ID, anchor, version = 4)]
+pub struct Pool {
+    pub reserve: u64,
+}
+
+#[context]
+pub struct UpdateContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        migrate = ConfigV1,
+        payer = payer
+    )]
+    pub config: Mut<Account<Config>>,
+}
+
+fn assert_migrate<T: Migrate<U>, U: Versioned + Pod>() {}
+
+pub fn main() {
+    assert_eq!(ConfigV1::DISCRIMINATOR, b"config\x01\0");
+    assert_eq!(Config::DISCRIMINATOR, b"config\x02\0");
+    assert_eq!(Config::VERSION, 2);
+    assert_eq!(Position::DISCRIMINATOR, &[1, 2, 3, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        Pool::DISCRIMINATOR,
+        &[241, 154, 109, 4, 17, 177, 109, 188, 4, 0, 0, 0, 0, 0, 0, 0]
+    );
+
+    assert_migrate::<Config, ConfigV1>();
+}
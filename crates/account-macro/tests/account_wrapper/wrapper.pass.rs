@@ -0,0 +1,52 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    std::marker::PhantomData,
+    typhoon::{
+        prelude::*,
+        program::{pubkey::Pubkey, RawAccountInfo},
+    },
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[derive(AccountWrapper)]
+pub struct Admin<'a, T: ReadableAccount>(Signer<'a, T>);
+
+#[derive(AccountWrapper)]
+pub struct Checked<'a, T: ReadableAccount> {
+    account: T,
+    _phantom: PhantomData<&'a ()>,
+}
+
+#[account]
+pub struct Data {
+    pub value: u64,
+}
+
+/// Wraps the account of a data type, written with its lifetime in a context.
+#[derive(AccountWrapper)]
+pub struct Filled<'a, T: Owner + AccountData>(Account<'a, T>);
+
+impl<'a, T: Owner + AccountData> FromAccountInfo<'a> for Filled<'a, T> {
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        Ok(Filled(Account::try_from_info(info)?))
+    }
+}
+
+#[context]
+pub struct WrapperContext {
+    pub admin: Signer,
+    pub data: Filled<'info, Data>,
+}
+
+fn assert_signer<T: SignerAccount>() {}
+fn assert_writable<T: WritableAccount>() {}
+
+pub fn main() {
+    assert_signer::<Admin<UncheckedAccount>>();
+    assert_writable::<Checked<Mut<SystemAccount>>>();
+    assert_signer::<Mut<Checked<Signer>>>();
+}
@@ -0,0 +1,29 @@
+use typhoon::{prelude::*, program::pubkey::Pubkey};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[context]
+pub struct UnknownConstraint {
+    pub authority: Signer,
+    #[constraint(token::owner = authority)]
+    pub token_account: TokenAccount,
+}
+
+#[context]
+pub struct MissingValue {
+    pub mint: Mint,
+    #[constraint(token::mint mint)]
+    pub token_account: TokenAccount,
+}
+
+#[context]
+pub struct MissingAssociatedAuthority {
+    pub mint: Mint,
+    #[constraint(associated_token::mint = mint)]
+    pub token_account: TokenAccount,
+}
+
+pub fn main() {}
@@ -0,0 +1,48 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[account]
+pub struct Treasury {
+    pub threshold: u8,
+    #[tail]
+    pub members: [Pubkey],
+}
+
+impl MultisigData for Treasury {
+    fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    fn members(&self) -> &[Pubkey] {
+        &self.members
+    }
+}
+
+#[context]
+pub struct WithdrawContext {
+    pub recipient: Mut<SystemAccount>,
+}
+
+pub fn withdraw(
+    amount: Args<u64>,
+    ctx: WithdrawContext,
+    treasury: Multisig<Mut<Account<Treasury>>>,
+) -> Result<(), ProgramError> {
+    treasury.send(&ctx.recipient, *amount)
+}
+
+fn assert_handler<'a, T, H: Handler<'a, T>>(_handler: H) {}
+
+fn assert_signer<T: SignerAccount + WritableAccount>() {}
+
+pub fn main() {
+    assert_handler(withdraw);
+    assert_signer::<Multisig<Mut<Account<Treasury>>>>();
+}
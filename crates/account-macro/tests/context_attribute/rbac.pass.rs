@@ -0,0 +1,54 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+pub struct CurrentProgram;
+
+impl ProgramId for CurrentProgram {
+    const ID: Pubkey = ID;
+}
+
+#[account]
+pub struct Vault {
+    pub amount: u64,
+}
+
+#[context]
+pub struct WithdrawContext {
+    #[constraint(pausable)]
+    pub registry: Singleton<RoleRegistry<CurrentProgram>>,
+    pub vault: Mut<Account<Vault>>,
+}
+
+pub fn withdraw(
+    _auth: Authority<Operator, CurrentProgram>,
+    ctx: WithdrawContext,
+) -> Result<(), ProgramError> {
+    ctx.vault.mut_data()?.amount = 0;
+
+    Ok(())
+}
+
+fn assert_handler<'a, T, H: Handler<'a, T>>(_handler: H) {}
+
+pub fn main() {
+    assert_eq!(
+        RoleRegistry::<CurrentProgram>::space(1),
+        core::mem::size_of::<RoleRegistryHeader>() + core::mem::size_of::<RoleMember>()
+    );
+
+    assert_handler(withdraw);
+    assert_handler(initialize_roles::<CurrentProgram>);
+    assert_handler(grant_role::<CurrentProgram>);
+    assert_handler(revoke_role::<CurrentProgram>);
+    assert_handler(transfer_ownership::<CurrentProgram>);
+    assert_handler(accept_ownership::<CurrentProgram>);
+    assert_handler(pause::<CurrentProgram>);
+    assert_handler(unpause::<CurrentProgram>);
+}
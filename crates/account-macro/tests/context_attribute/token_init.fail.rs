@@ -0,0 +1,39 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+#[context]
+pub struct InterfaceTokenInit {
+    pub payer: Mut<Signer>,
+    pub mint: InterfaceAccount<MintData>,
+    #[constraint(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = payer
+    )]
+    pub account: Mut<InterfaceAccount<TokenAccountData>>,
+    pub system: Program<System>,
+    pub token_program: Interface<TokenInterface>,
+}
+
+#[context]
+pub struct MissingTokenProgram {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = payer
+    )]
+    pub mint: Mut<Mint>,
+    pub system: Program<System>,
+}
+
+pub fn main() {}
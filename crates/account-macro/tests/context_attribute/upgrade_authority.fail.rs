@@ -0,0 +1,24 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+pub struct CurrentProgram;
+
+impl ProgramId for CurrentProgram {
+    const ID: Pubkey = ID;
+}
+
+#[context]
+pub struct UnsignedContext {
+    pub admin: UncheckedAccount,
+    #[constraint(upgrade_authority = admin)]
+    pub program_data: ProgramData<CurrentProgram>,
+}
+
+pub fn main() {}
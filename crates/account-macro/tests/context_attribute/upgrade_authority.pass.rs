@@ -0,0 +1,42 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::{prelude::*, program::pubkey::Pubkey},
+};
+
+pub const ID: Pubkey = [
+    218, 7, 92, 178, 255, 94, 198, 129, 118, 19, 222, 83, 11, 105, 42, 135, 53, 71, 119, 105, 218,
+    71, 67, 12, 189, 129, 84, 51, 92, 74, 131, 39,
+];
+
+pub struct CurrentProgram;
+
+impl ProgramId for CurrentProgram {
+    const ID: Pubkey = ID;
+}
+
+#[account(singleton)]
+pub struct Config {
+    pub fee: u64,
+}
+
+#[context]
+pub struct InitContext {
+    pub admin: Mut<Signer>,
+    #[constraint(upgrade_authority = admin)]
+    pub program_data: ProgramData<CurrentProgram>,
+    #[constraint(
+        init,
+        payer = admin,
+        space = core::mem::size_of::<Config>()
+    )]
+    pub config: Mut<Singleton<Config>>,
+    pub system: Program<System>,
+}
+
+fn assert_readable<'a, T: FromAccountInfo<'a> + ReadableAccount>() {}
+
+pub fn main() {
+    assert_eq!(PROGRAM_DATA_METADATA_LEN, 45);
+
+    assert_readable::<ProgramData<CurrentProgram>>();
+}
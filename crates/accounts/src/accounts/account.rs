@@ -1,16 +1,13 @@
 use {
-    crate::{Discriminator, FromAccountInfo, Owner, ReadableAccount},
-    bytemuck::Pod,
+    crate::{AccountData, FromAccountInfo, Owner, ReadableAccount},
     std::marker::PhantomData,
     typhoon_errors::Error,
-    typhoon_program::{
-        bytes::try_from_bytes, program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref,
-    },
+    typhoon_program::{program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref},
 };
 
 pub struct Account<'a, T>
 where
-    T: Pod + Discriminator,
+    T: AccountData + ?Sized,
 {
     info: &'a RawAccountInfo,
     _phantom: PhantomData<T>,
@@ -18,13 +15,15 @@ where
 
 impl<'a, T> FromAccountInfo<'a> for Account<'a, T>
 where
-    T: Owner + Pod + Discriminator,
+    T: Owner + AccountData + ?Sized,
 {
     fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
         if info.owner() != &T::OWNER {
             return Err(Error::AccountOwnedByWrongProgram.into());
         }
 
+        check_discriminator::<T>(&info.try_borrow_data()?)?;
+
         Ok(Account {
             info,
             _phantom: PhantomData,
@@ -34,7 +33,7 @@ where
 
 impl<T> AsRef<RawAccountInfo> for Account<'_, T>
 where
-    T: Pod + Discriminator,
+    T: AccountData + ?Sized,
 {
     fn as_ref(&self) -> &RawAccountInfo {
         self.info
@@ -43,7 +42,7 @@ where
 
 impl<T> ReadableAccount for Account<'_, T>
 where
-    T: Pod + Discriminator,
+    T: AccountData + ?Sized,
 {
     type DataType = T;
 
@@ -60,12 +59,25 @@ where
     }
 
     fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
-        let dis_len = T::DISCRIMINATOR.len();
         let data = self.info.try_borrow_data()?;
 
-        Ref::filter_map(data, |data| {
-            try_from_bytes(&data[dis_len..std::mem::size_of::<T>() + dis_len])
-        })
-        .map_err(|_| ProgramError::InvalidAccountData)
+        Ref::filter_map(data, |data| T::read(data.get(T::DISCRIMINATOR.len()..)?))
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// Checks the data starts with the discriminator of `T` and is large enough to hold it.
+pub(crate) fn check_discriminator<T>(data: &[u8]) -> Result<(), ProgramError>
+where
+    T: AccountData + ?Sized,
+{
+    if data.len() < T::DISCRIMINATOR.len() + T::MIN_LEN {
+        return Err(ProgramError::AccountDataTooSmall);
     }
+
+    if !data.starts_with(T::DISCRIMINATOR) {
+        return Err(Error::AccountDiscriminatorMismatch.into());
+    }
+
+    Ok(())
 }
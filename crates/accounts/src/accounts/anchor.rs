@@ -0,0 +1,89 @@
+use {
+    super::account::check_discriminator,
+    crate::{Discriminator, FromAccountInfo, Owner, ReadableAccount},
+    bytemuck::Pod,
+    std::marker::PhantomData,
+    typhoon_errors::Error,
+    typhoon_program::{
+        bytes::try_from_bytes, program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref,
+    },
+};
+
+/// Length of the discriminator Anchor prepends to its accounts.
+pub const ANCHOR_DISCRIMINATOR_LEN: usize = 8;
+
+/// An account of an Anchor program, described with `#[account(owner = ..., anchor)]`.
+///
+/// Checks:
+/// * `account_info.owner == T::OWNER`
+/// * `T::DISCRIMINATOR` is the 8 bytes discriminator written by Anchor
+/// * `account_info.data` starts with `T::DISCRIMINATOR` and can hold `T`
+pub struct AnchorAccount<'a, T>
+where
+    T: Pod + Discriminator,
+{
+    info: &'a RawAccountInfo,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for AnchorAccount<'a, T>
+where
+    T: Owner + Pod + Discriminator,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.owner() != &T::OWNER {
+            return Err(Error::AccountOwnedByWrongProgram.into());
+        }
+
+        if T::DISCRIMINATOR.len() != ANCHOR_DISCRIMINATOR_LEN {
+            return Err(Error::AccountDiscriminatorMismatch.into());
+        }
+
+        check_discriminator::<T>(&info.try_borrow_data()?)?;
+
+        Ok(AnchorAccount {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for AnchorAccount<'_, T>
+where
+    T: Pod + Discriminator,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for AnchorAccount<'_, T>
+where
+    T: Pod + Discriminator,
+{
+    type DataType = T;
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| {
+            try_from_bytes(
+                &data
+                    [ANCHOR_DISCRIMINATOR_LEN..std::mem::size_of::<T>() + ANCHOR_DISCRIMINATOR_LEN],
+            )
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
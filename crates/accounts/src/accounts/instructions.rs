@@ -0,0 +1,108 @@
+use {
+    crate::{FromAccountInfo, ReadableAccount},
+    typhoon_errors::Error,
+    typhoon_program::{
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::instructions::{is_top_level, Instructions, IntrospectedInstruction, ID},
+        RawAccountInfo, Ref,
+    },
+};
+
+/// The instructions sysvar, to inspect the other instructions of the transaction.
+///
+/// The data stays borrowed for the lifetime of the account, the sysvar being read-only.
+///
+/// Checks:
+/// * `account_info.key == sysvars::instructions::ID`
+pub struct InstructionsSysvar<'a> {
+    info: &'a RawAccountInfo,
+    data: Ref<'a, [u8]>,
+}
+
+impl<'a> FromAccountInfo<'a> for InstructionsSysvar<'a> {
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.key() != &ID {
+            return Err(Error::AccountAddressMismatch.into());
+        }
+
+        let data = info.try_borrow_data()?;
+        Instructions::new(&data)?;
+
+        Ok(InstructionsSysvar { info, data })
+    }
+}
+
+impl InstructionsSysvar<'_> {
+    fn instructions(&self) -> Result<Instructions<'_>, ProgramError> {
+        Instructions::new(&self.data)
+    }
+
+    /// Number of top-level instructions of the transaction.
+    pub fn len(&self) -> Result<usize, ProgramError> {
+        self.instructions()?.len()
+    }
+
+    pub fn is_empty(&self) -> Result<bool, ProgramError> {
+        self.instructions()?.is_empty()
+    }
+
+    /// Index of the top-level instruction being executed.
+    pub fn current_index(&self) -> Result<u16, ProgramError> {
+        self.instructions()?.current_index()
+    }
+
+    pub fn load_instruction_at(
+        &self,
+        index: usize,
+    ) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.instructions()?.load_instruction_at(index)
+    }
+
+    pub fn load_current_instruction(&self) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.instructions()?.load_current_instruction()
+    }
+
+    /// Loads the instruction at `offset` from the current one, negative offsets being before it.
+    pub fn get_instruction_relative(
+        &self,
+        offset: i64,
+    ) -> Result<IntrospectedInstruction<'_>, ProgramError> {
+        self.instructions()?.get_instruction_relative(offset)
+    }
+
+    /// Ensures `program_id` is invoked by the current top-level instruction and not through a CPI.
+    pub fn assert_top_level(&self, program_id: &Pubkey) -> Result<(), ProgramError> {
+        if !is_top_level() || self.load_current_instruction()?.program_id() != program_id {
+            return Err(Error::NotTopLevelInstruction.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl AsRef<RawAccountInfo> for InstructionsSysvar<'_> {
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl ReadableAccount for InstructionsSysvar<'_> {
+    type DataType = [u8];
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        self.info.try_borrow_data()
+    }
+}
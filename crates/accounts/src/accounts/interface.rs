@@ -0,0 +1,157 @@
+use {
+    crate::{
+        extensions::{find_extension, Extension},
+        FromAccountInfo, InterfaceData, ProgramIds, ReadableAccount,
+    },
+    std::marker::PhantomData,
+    typhoon_errors::Error,
+    typhoon_program::{
+        bytes::try_from_bytes, program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref,
+    },
+};
+
+///
+/// Checks:
+/// * `account_info.key` is one of `T::IDS`
+/// * `account_info.executable == true`
+pub struct Interface<'a, T>
+where
+    T: ProgramIds,
+{
+    info: &'a RawAccountInfo,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for Interface<'a, T>
+where
+    T: ProgramIds,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if !T::IDS.contains(info.key()) {
+            return Err(Error::InvalidProgramId.into());
+        }
+
+        if !info.executable() {
+            return Err(Error::InvalidProgramExecutable.into());
+        }
+
+        Ok(Interface {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for Interface<'_, T>
+where
+    T: ProgramIds,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for Interface<'_, T>
+where
+    T: ProgramIds,
+{
+    type DataType = [u8];
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        self.info.try_borrow_data()
+    }
+}
+
+///
+/// Checks:
+/// * `account_info.owner` is one of `T::OWNERS`
+/// * `T::is_valid(account_info.owner, account_info.data)`
+pub struct InterfaceAccount<'a, T>
+where
+    T: InterfaceData,
+{
+    info: &'a RawAccountInfo,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for InterfaceAccount<'a, T>
+where
+    T: InterfaceData,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if !T::OWNERS.contains(info.owner()) {
+            return Err(Error::AccountOwnedByWrongProgram.into());
+        }
+
+        if !T::is_valid(info.owner(), &info.try_borrow_data()?) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(InterfaceAccount {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> InterfaceAccount<'_, T>
+where
+    T: InterfaceData,
+{
+    /// Reads the extension `E` of the account, `None` if it doesn't have one.
+    pub fn extension<E: Extension>(&self) -> Result<Option<Ref<E>>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ok(Ref::filter_map(data, find_extension::<E>).ok())
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for InterfaceAccount<'_, T>
+where
+    T: InterfaceData,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for InterfaceAccount<'_, T>
+where
+    T: InterfaceData,
+{
+    type DataType = T;
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        let dis_len = T::DISCRIMINATOR.len();
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| {
+            try_from_bytes(&data[dis_len..std::mem::size_of::<T>() + dis_len])
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
@@ -1,8 +1,19 @@
 mod account;
+mod anchor;
+mod instructions;
+mod interface;
+mod multisig;
 mod mutable;
 mod program;
+mod program_data;
 mod signer;
+mod singleton;
 mod system;
+mod sysvar;
+mod token;
 mod unchecked;
 
-pub use {account::*, mutable::*, program::*, signer::*, system::*, unchecked::*};
+pub use {
+    account::*, anchor::*, instructions::*, interface::*, multisig::*, mutable::*, program::*,
+    program_data::*, signer::*, singleton::*, system::*, sysvar::*, token::*, unchecked::*,
+};
@@ -0,0 +1,116 @@
+use {
+    crate::{MultisigData, ReadableAccount, SignerAccount, WritableAccount},
+    typhoon_errors::Error,
+    typhoon_program::{program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref, RefMut},
+};
+
+/// An account holding a `MultisigData` config, signing when enough of its members signed the
+/// transaction. `T` is the account itself, like `Mut<Account<'a, U>>` to move its lamports.
+///
+/// Only the handlers taking the `Multisig` check the threshold: loaded as `T` directly, the
+/// account moves its lamports without any signature. Every handler paying from it has to take it
+/// through `Multisig`.
+///
+/// Checks:
+/// * the checks of `T`
+/// * the threshold is between 1 and the number of members
+/// * at least `threshold` distinct members are among the signers, each of them counted once
+pub struct Multisig<'a, T>
+where
+    T: ReadableAccount,
+{
+    account: T,
+    signers: &'a [RawAccountInfo],
+}
+
+impl<'a, T> Multisig<'a, T>
+where
+    T: ReadableAccount,
+    T::DataType: MultisigData,
+{
+    /// Checks the threshold of `account` against the members found in `signers`.
+    pub fn try_new(account: T, signers: &'a [RawAccountInfo]) -> Result<Self, ProgramError> {
+        {
+            let data = account.data()?;
+            let members = data.members();
+            let threshold = data.threshold() as usize;
+            if threshold == 0 || threshold > members.len() {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            // A key listed several times approves once
+            let approvals = members
+                .iter()
+                .enumerate()
+                .filter(|(i, member)| {
+                    !members[..*i].contains(member)
+                        && signers
+                            .iter()
+                            .any(|signer| signer.is_signer() && signer.key() == *member)
+                })
+                .count();
+
+            if approvals < threshold {
+                return Err(Error::MultisigThresholdNotMet.into());
+            }
+        }
+
+        Ok(Multisig { account, signers })
+    }
+
+    /// The accounts the threshold was checked against, to forward them to a CPI.
+    pub fn signers(&self) -> &'a [RawAccountInfo] {
+        self.signers
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for Multisig<'_, T>
+where
+    T: ReadableAccount,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.account.as_ref()
+    }
+}
+
+impl<T> SignerAccount for Multisig<'_, T> where T: ReadableAccount {}
+
+impl<T> ReadableAccount for Multisig<'_, T>
+where
+    T: ReadableAccount,
+{
+    type DataType = T::DataType;
+
+    fn key(&self) -> &Pubkey {
+        self.account.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.account.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.account.lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        self.account.data()
+    }
+}
+
+impl<T> WritableAccount for Multisig<'_, T>
+where
+    T: WritableAccount,
+{
+    fn realloc(&self, new_len: usize, zero_init: bool) -> Result<(), ProgramError> {
+        self.account.realloc(new_len, zero_init)
+    }
+
+    fn mut_lamports(&self) -> Result<RefMut<u64>, ProgramError> {
+        self.account.mut_lamports()
+    }
+
+    fn mut_data(&self) -> Result<RefMut<Self::DataType>, ProgramError> {
+        self.account.mut_data()
+    }
+}
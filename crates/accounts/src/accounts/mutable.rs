@@ -1,5 +1,5 @@
 use {
-    crate::{FromAccountInfo, ReadMut, ReadableAccount, Signer, SignerAccount, WritableAccount},
+    crate::{FromAccountInfo, ReadMut, ReadableAccount, SignerAccount, WritableAccount},
     typhoon_errors::Error,
     typhoon_program::{program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref, RefMut},
 };
@@ -72,4 +72,4 @@ where
     }
 }
 
-impl SignerAccount for Mut<Signer<'_>> {}
+impl<T> SignerAccount for Mut<T> where T: SignerAccount {}
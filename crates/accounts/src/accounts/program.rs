@@ -23,11 +23,11 @@ where
 {
     fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
         if info.key() != &T::ID {
-            return Err(Error::AccountOwnedByWrongProgram.into());
+            return Err(Error::InvalidProgramId.into());
         }
 
         if !info.executable() {
-            return Err(Error::AccountOwnedByWrongProgram.into());
+            return Err(Error::InvalidProgramExecutable.into());
         }
 
         Ok(Program {
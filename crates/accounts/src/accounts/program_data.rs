@@ -0,0 +1,114 @@
+use {
+    crate::{BpfLoaderUpgradeable, FromAccountInfo, ProgramId, ReadableAccount},
+    std::marker::PhantomData,
+    typhoon_errors::Error,
+    typhoon_program::{
+        program_error::ProgramError, pubkey::Pubkey, pubkey_from_array, RawAccountInfo, Ref,
+    },
+};
+
+/// Length of the header preceding the program bytes: the state tag, the slot of the last
+/// deployment and the optional upgrade authority.
+pub const PROGRAM_DATA_METADATA_LEN: usize = 45;
+
+/// Tag of the `ProgramData` variant of the upgradeable loader state.
+const PROGRAM_DATA_TAG: [u8; 4] = 3u32.to_le_bytes();
+
+/// The account of the upgradeable loader holding the bytes and the upgrade authority of the
+/// program `T`, usually the program itself with the marker declared by `program_id!`.
+///
+/// Checks:
+/// * `account_info.owner == BpfLoaderUpgradeable::ID`
+/// * `account_info.key == BpfLoaderUpgradeable::find_program_data_address(&T::ID).0`
+/// * `account_info.data` holds a `ProgramData` state
+pub struct ProgramData<'a, T>
+where
+    T: ProgramId,
+{
+    info: &'a RawAccountInfo,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for ProgramData<'a, T>
+where
+    T: ProgramId,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.owner() != &BpfLoaderUpgradeable::ID {
+            return Err(Error::AccountOwnedByWrongProgram.into());
+        }
+
+        if info.key() != &BpfLoaderUpgradeable::find_program_data_address(&T::ID).0 {
+            return Err(Error::AccountAddressMismatch.into());
+        }
+
+        let data = info.try_borrow_data()?;
+        if data.len() < PROGRAM_DATA_METADATA_LEN || data[..4] != PROGRAM_DATA_TAG {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(ProgramData {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> ProgramData<'_, T>
+where
+    T: ProgramId,
+{
+    /// Slot of the last deployment of the program.
+    pub fn slot(&self) -> Result<u64, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+        let mut slot = [0; 8];
+        slot.copy_from_slice(&data[4..12]);
+
+        Ok(u64::from_le_bytes(slot))
+    }
+
+    /// Authority allowed to upgrade the program, `None` once the program is immutable.
+    pub fn upgrade_authority_address(&self) -> Result<Option<Pubkey>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+        if data[12] == 0 {
+            return Ok(None);
+        }
+
+        let mut authority = [0; 32];
+        authority.copy_from_slice(&data[13..PROGRAM_DATA_METADATA_LEN]);
+
+        Ok(Some(pubkey_from_array(authority)))
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for ProgramData<'_, T>
+where
+    T: ProgramId,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for ProgramData<'_, T>
+where
+    T: ProgramId,
+{
+    type DataType = [u8];
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        self.info.try_borrow_data()
+    }
+}
@@ -1,47 +1,69 @@
 use {
-    crate::{FromAccountInfo, ReadableAccount, SignerAccount},
+    crate::{FromAccountInfo, ReadableAccount, SignerAccount, UncheckedAccount},
+    std::marker::PhantomData,
     typhoon_errors::Error,
     typhoon_program::{program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref},
 };
 
-pub struct Signer<'a> {
-    info: &'a RawAccountInfo,
+/// A signer of the transaction, with the checks and the data of `T`.
+///
+/// Checks:
+/// * `account_info.is_signer == true`
+/// * the checks of `T`
+pub struct Signer<'a, T = UncheckedAccount<'a>>
+where
+    T: ReadableAccount,
+{
+    account: T,
+    _phantom: PhantomData<&'a ()>,
 }
 
-impl<'a> FromAccountInfo<'a> for Signer<'a> {
+impl<'a, T> FromAccountInfo<'a> for Signer<'a, T>
+where
+    T: FromAccountInfo<'a> + ReadableAccount,
+{
     fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
         if !info.is_signer() {
             return Err(Error::AccountNotSigner.into());
         }
 
-        Ok(Signer { info })
+        Ok(Signer {
+            account: T::try_from_info(info)?,
+            _phantom: PhantomData,
+        })
     }
 }
 
-impl AsRef<RawAccountInfo> for Signer<'_> {
+impl<T> AsRef<RawAccountInfo> for Signer<'_, T>
+where
+    T: ReadableAccount,
+{
     fn as_ref(&self) -> &RawAccountInfo {
-        self.info
+        self.account.as_ref()
     }
 }
 
-impl SignerAccount for Signer<'_> {}
+impl<T> SignerAccount for Signer<'_, T> where T: ReadableAccount {}
 
-impl ReadableAccount for Signer<'_> {
-    type DataType = [u8];
+impl<T> ReadableAccount for Signer<'_, T>
+where
+    T: ReadableAccount,
+{
+    type DataType = T::DataType;
 
     fn key(&self) -> &Pubkey {
-        self.info.key()
+        self.account.key()
     }
 
     fn owner(&self) -> &Pubkey {
-        self.info.owner()
+        self.account.owner()
     }
 
     fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
-        self.info.try_borrow_lamports()
+        self.account.lamports()
     }
 
     fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
-        self.info.try_borrow_data()
+        self.account.data()
     }
 }
@@ -0,0 +1,47 @@
+use {
+    crate::{Account, AccountWrapper, FromAccountInfo, SingletonData},
+    typhoon_errors::Error,
+    typhoon_program::{program_error::ProgramError, RawAccountInfo},
+};
+
+/// The account of a program holding its `#[account(singleton)]` data, like a global config.
+///
+/// Checks:
+/// * `account_info.key == T::find_address().0`
+/// * the checks of `Account<T>`
+pub struct Singleton<'a, T>(Account<'a, T>)
+where
+    T: SingletonData + ?Sized;
+
+impl<'a, T> FromAccountInfo<'a> for Singleton<'a, T>
+where
+    T: SingletonData + ?Sized,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.key() != &T::find_address().0 {
+            return Err(Error::AccountAddressMismatch.into());
+        }
+
+        Ok(Singleton(Account::try_from_info(info)?))
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for Singleton<'_, T>
+where
+    T: SingletonData + ?Sized,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.0.as_ref()
+    }
+}
+
+impl<'a, T> AccountWrapper for Singleton<'a, T>
+where
+    T: SingletonData + ?Sized,
+{
+    type Inner = Account<'a, T>;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.0
+    }
+}
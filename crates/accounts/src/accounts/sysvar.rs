@@ -0,0 +1,95 @@
+use {
+    crate::{FromAccountInfo, ReadableAccount},
+    std::marker::PhantomData,
+    typhoon_errors::Error,
+    typhoon_program::{
+        bytes::try_from_bytes,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        sysvars::{
+            slot_hashes::{SlotHash, SlotHashes},
+            SysvarId,
+        },
+        RawAccountInfo, Ref,
+    },
+};
+
+///
+/// Checks:
+/// * `account_info.key == T::ID`
+pub struct Sysvar<'a, T>
+where
+    T: SysvarId,
+{
+    info: &'a RawAccountInfo,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T> FromAccountInfo<'a> for Sysvar<'a, T>
+where
+    T: SysvarId,
+{
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.key() != &T::ID {
+            return Err(Error::AccountAddressMismatch.into());
+        }
+
+        Ok(Sysvar {
+            info,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<T> AsRef<RawAccountInfo> for Sysvar<'_, T>
+where
+    T: SysvarId,
+{
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl<T> ReadableAccount for Sysvar<'_, T>
+where
+    T: SysvarId + Copy,
+{
+    type DataType = T;
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| {
+            try_from_bytes(data.get(..core::mem::size_of::<T>())?)
+        })
+        .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl Sysvar<'_, SlotHashes> {
+    /// Recent slot hashes, sorted by descending slot.
+    pub fn entries(&self) -> Result<Ref<[SlotHash]>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, SlotHashes::entries).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Returns the hash of `slot` if it is still in the sysvar.
+    pub fn get_hash(&self, slot: u64) -> Result<Option<[u8; 32]>, ProgramError> {
+        let entries = self.entries()?;
+
+        Ok(SlotHashes::get(&entries, slot).copied())
+    }
+}
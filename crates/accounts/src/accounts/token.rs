@@ -0,0 +1,260 @@
+use {
+    crate::{
+        extensions::{has_extensions, ACCOUNT_TYPE_ACCOUNT, ACCOUNT_TYPE_MINT},
+        Discriminator, FromAccountInfo, InterfaceData, ProgramId, ReadableAccount,
+        Token2022Program, TokenProgram,
+    },
+    bytemuck::{Pod, Zeroable},
+    typhoon_errors::Error,
+    typhoon_program::{
+        bytes::try_from_bytes, program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref,
+    },
+};
+
+/// Reads a `COption<Pubkey>` as laid out by the Token program.
+fn read_option_key<'a>(tag: &[u8; 4], key: &'a Pubkey) -> Option<&'a Pubkey> {
+    (u32::from_le_bytes(*tag) == 1).then_some(key)
+}
+
+/// State of a token account, laid out exactly like `spl_token::state::Account`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TokenAccountData {
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: [u8; 8],
+    delegate_tag: [u8; 4],
+    delegate: Pubkey,
+    state: u8,
+    is_native_tag: [u8; 4],
+    is_native: [u8; 8],
+    delegated_amount: [u8; 8],
+    close_authority_tag: [u8; 4],
+    close_authority: Pubkey,
+}
+
+unsafe impl Zeroable for TokenAccountData {}
+unsafe impl Pod for TokenAccountData {}
+
+impl Discriminator for TokenAccountData {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+impl InterfaceData for TokenAccountData {
+    const OWNERS: &'static [Pubkey] = &[TokenProgram::ID, Token2022Program::ID];
+
+    fn is_valid(owner: &Pubkey, data: &[u8]) -> bool {
+        (data.len() == Self::LEN || has_extensions(owner, data, ACCOUNT_TYPE_ACCOUNT))
+            && try_from_bytes::<Self>(&data[..Self::LEN]).is_some_and(Self::is_initialized)
+    }
+}
+
+impl TokenAccountData {
+    pub const LEN: usize = core::mem::size_of::<TokenAccountData>();
+
+    pub fn mint(&self) -> &Pubkey {
+        &self.mint
+    }
+
+    pub fn owner(&self) -> &Pubkey {
+        &self.owner
+    }
+
+    pub fn amount(&self) -> u64 {
+        u64::from_le_bytes(self.amount)
+    }
+
+    pub fn delegate(&self) -> Option<&Pubkey> {
+        read_option_key(&self.delegate_tag, &self.delegate)
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.state != 0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.state == 2
+    }
+
+    /// Returns the rent-exempt reserve if this is a wrapped SOL account.
+    pub fn is_native(&self) -> Option<u64> {
+        (u32::from_le_bytes(self.is_native_tag) == 1).then(|| u64::from_le_bytes(self.is_native))
+    }
+
+    pub fn delegated_amount(&self) -> u64 {
+        u64::from_le_bytes(self.delegated_amount)
+    }
+
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        read_option_key(&self.close_authority_tag, &self.close_authority)
+    }
+}
+
+/// State of a mint, laid out exactly like `spl_token::state::Mint`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MintData {
+    mint_authority_tag: [u8; 4],
+    mint_authority: Pubkey,
+    supply: [u8; 8],
+    decimals: u8,
+    is_initialized: u8,
+    freeze_authority_tag: [u8; 4],
+    freeze_authority: Pubkey,
+}
+
+unsafe impl Zeroable for MintData {}
+unsafe impl Pod for MintData {}
+
+impl Discriminator for MintData {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+impl InterfaceData for MintData {
+    const OWNERS: &'static [Pubkey] = &[TokenProgram::ID, Token2022Program::ID];
+
+    fn is_valid(owner: &Pubkey, data: &[u8]) -> bool {
+        (data.len() == Self::LEN || has_extensions(owner, data, ACCOUNT_TYPE_MINT))
+            && try_from_bytes::<Self>(&data[..Self::LEN]).is_some_and(Self::is_initialized)
+    }
+}
+
+impl MintData {
+    pub const LEN: usize = core::mem::size_of::<MintData>();
+
+    pub fn mint_authority(&self) -> Option<&Pubkey> {
+        read_option_key(&self.mint_authority_tag, &self.mint_authority)
+    }
+
+    pub fn supply(&self) -> u64 {
+        u64::from_le_bytes(self.supply)
+    }
+
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized != 0
+    }
+
+    pub fn freeze_authority(&self) -> Option<&Pubkey> {
+        read_option_key(&self.freeze_authority_tag, &self.freeze_authority)
+    }
+}
+
+///
+/// Checks:
+/// * `account_info.owner == TokenProgram::ID`
+/// * `account_info.data_len == TokenAccountData::LEN`
+/// * the account is initialized
+pub struct TokenAccount<'a> {
+    info: &'a RawAccountInfo,
+}
+
+impl<'a> FromAccountInfo<'a> for TokenAccount<'a> {
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.owner() != &TokenProgram::ID {
+            return Err(Error::AccountOwnedByWrongProgram.into());
+        }
+
+        if info.data_len() != TokenAccountData::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !try_from_bytes::<TokenAccountData>(&info.try_borrow_data()?)
+            .is_some_and(TokenAccountData::is_initialized)
+        {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(TokenAccount { info })
+    }
+}
+
+impl AsRef<RawAccountInfo> for TokenAccount<'_> {
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl ReadableAccount for TokenAccount<'_> {
+    type DataType = TokenAccountData;
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| try_from_bytes(&data[..TokenAccountData::LEN]))
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+///
+/// Checks:
+/// * `account_info.owner == TokenProgram::ID`
+/// * `account_info.data_len == MintData::LEN`
+/// * the mint is initialized
+pub struct Mint<'a> {
+    info: &'a RawAccountInfo,
+}
+
+impl<'a> FromAccountInfo<'a> for Mint<'a> {
+    fn try_from_info(info: &'a RawAccountInfo) -> Result<Self, ProgramError> {
+        if info.owner() != &TokenProgram::ID {
+            return Err(Error::AccountOwnedByWrongProgram.into());
+        }
+
+        if info.data_len() != MintData::LEN {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !try_from_bytes::<MintData>(&info.try_borrow_data()?)
+            .is_some_and(MintData::is_initialized)
+        {
+            return Err(ProgramError::UninitializedAccount);
+        }
+
+        Ok(Mint { info })
+    }
+}
+
+impl AsRef<RawAccountInfo> for Mint<'_> {
+    fn as_ref(&self) -> &RawAccountInfo {
+        self.info
+    }
+}
+
+impl ReadableAccount for Mint<'_> {
+    type DataType = MintData;
+
+    fn key(&self) -> &Pubkey {
+        self.info.key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.info.owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.info.try_borrow_lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        let data = self.info.try_borrow_data()?;
+
+        Ref::filter_map(data, |data| try_from_bytes(&data[..MintData::LEN]))
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
@@ -0,0 +1,236 @@
+//! Zero-copy readers of the Token-2022 extensions, stored as TLV entries after the base state.
+
+use {
+    crate::{ProgramId, Token2022Program, TokenAccountData},
+    bytemuck::{Pod, Zeroable},
+    typhoon_program::{bytes::try_from_bytes, pubkey::Pubkey},
+};
+
+pub(crate) const ACCOUNT_TYPE_MINT: u8 = 1;
+pub(crate) const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// The account type byte follows the base state, padded to the length of a token account.
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccountData::LEN;
+const TLV_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+const TLV_HEADER_LEN: usize = 4;
+
+/// Length of a Token multisig, which an account with extensions never has.
+const MULTISIG_LEN: usize = 355;
+
+/// Checks the data is a Token-2022 mint or account with extensions, of the account type `ty`.
+pub(crate) fn has_extensions(owner: &Pubkey, data: &[u8], ty: u8) -> bool {
+    owner == &Token2022Program::ID
+        && data.len() > ACCOUNT_TYPE_OFFSET
+        && data.len() != MULTISIG_LEN
+        && data[ACCOUNT_TYPE_OFFSET] == ty
+}
+
+/// A Token-2022 extension with its `ExtensionType` value.
+pub trait Extension: Pod {
+    const TYPE: u16;
+}
+
+/// Finds the extension `E` in the whole data of a Token-2022 mint or account.
+pub fn find_extension<E: Extension>(data: &[u8]) -> Option<&E> {
+    let mut offset = TLV_OFFSET;
+
+    while offset + TLV_HEADER_LEN <= data.len() {
+        let ty = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value = offset + TLV_HEADER_LEN;
+
+        // The remaining data is not initialized.
+        if ty == 0 {
+            return None;
+        }
+
+        if ty == E::TYPE {
+            return try_from_bytes(data.get(value..value + len)?);
+        }
+
+        offset = value + len;
+    }
+
+    None
+}
+
+/// Reads an `OptionalNonZeroPubkey`, the zero key meaning `None`.
+fn non_zero_key(key: &Pubkey) -> Option<&Pubkey> {
+    (key != &Pubkey::default()).then_some(key)
+}
+
+macro_rules! extension {
+    ($name:ident, $ty:expr) => {
+        unsafe impl Zeroable for $name {}
+        unsafe impl Pod for $name {}
+
+        impl Extension for $name {
+            const TYPE: u16 = $ty;
+        }
+    };
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransferFee {
+    epoch: [u8; 8],
+    maximum_fee: [u8; 8],
+    transfer_fee_basis_points: [u8; 2],
+}
+
+unsafe impl Zeroable for TransferFee {}
+unsafe impl Pod for TransferFee {}
+
+impl TransferFee {
+    const ONE_IN_BASIS_POINTS: u128 = 10_000;
+
+    /// First epoch where the fee applies.
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    pub fn maximum_fee(&self) -> u64 {
+        u64::from_le_bytes(self.maximum_fee)
+    }
+
+    pub fn transfer_fee_basis_points(&self) -> u16 {
+        u16::from_le_bytes(self.transfer_fee_basis_points)
+    }
+
+    /// Computes the fee withheld on a transfer of `amount`, rounded up like the Token-2022
+    /// program.
+    pub fn calculate_fee(&self, amount: u64) -> Option<u64> {
+        let basis_points = self.transfer_fee_basis_points() as u128;
+        if basis_points == 0 || amount == 0 {
+            return Some(0);
+        }
+
+        let numerator = (amount as u128).checked_mul(basis_points)?;
+        let fee = numerator
+            .checked_add(Self::ONE_IN_BASIS_POINTS - 1)?
+            .checked_div(Self::ONE_IN_BASIS_POINTS)?;
+
+        Some(u64::try_from(fee).ok()?.min(self.maximum_fee()))
+    }
+}
+
+/// Mint extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransferFeeConfig {
+    transfer_fee_config_authority: Pubkey,
+    withdraw_withheld_authority: Pubkey,
+    withheld_amount: [u8; 8],
+    older_transfer_fee: TransferFee,
+    newer_transfer_fee: TransferFee,
+}
+
+extension!(TransferFeeConfig, 1);
+
+impl TransferFeeConfig {
+    pub fn transfer_fee_config_authority(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.transfer_fee_config_authority)
+    }
+
+    pub fn withdraw_withheld_authority(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.withdraw_withheld_authority)
+    }
+
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+
+    /// Returns the fee in effect at `epoch`.
+    pub fn get_epoch_fee(&self, epoch: u64) -> &TransferFee {
+        if epoch >= self.newer_transfer_fee.epoch() {
+            &self.newer_transfer_fee
+        } else {
+            &self.older_transfer_fee
+        }
+    }
+}
+
+/// Account extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransferFeeAmount {
+    withheld_amount: [u8; 8],
+}
+
+extension!(TransferFeeAmount, 2);
+
+impl TransferFeeAmount {
+    pub fn withheld_amount(&self) -> u64 {
+        u64::from_le_bytes(self.withheld_amount)
+    }
+}
+
+/// Mint extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MintCloseAuthority {
+    close_authority: Pubkey,
+}
+
+extension!(MintCloseAuthority, 3);
+
+impl MintCloseAuthority {
+    pub fn close_authority(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.close_authority)
+    }
+}
+
+/// Mint extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransferHook {
+    authority: Pubkey,
+    program_id: Pubkey,
+}
+
+extension!(TransferHook, 14);
+
+impl TransferHook {
+    pub fn authority(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.authority)
+    }
+
+    pub fn program_id(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.program_id)
+    }
+}
+
+/// Account extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TransferHookAccount {
+    transferring: u8,
+}
+
+extension!(TransferHookAccount, 15);
+
+impl TransferHookAccount {
+    pub fn transferring(&self) -> bool {
+        self.transferring != 0
+    }
+}
+
+/// Mint extension.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct MetadataPointer {
+    authority: Pubkey,
+    metadata_address: Pubkey,
+}
+
+extension!(MetadataPointer, 18);
+
+impl MetadataPointer {
+    pub fn authority(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.authority)
+    }
+
+    pub fn metadata_address(&self) -> Option<&Pubkey> {
+        non_zero_key(&self.metadata_address)
+    }
+}
@@ -1,25 +1,170 @@
 mod accounts;
+pub mod extensions;
+mod precompiles;
 mod programs;
 mod readable;
 
-pub use {accounts::*, programs::*, readable::*};
+pub use {accounts::*, precompiles::*, programs::*, readable::*};
 use {
+    bytemuck::Pod,
     sealed::Sealed,
-    typhoon_program::{program_error::ProgramError, pubkey::Pubkey, RawAccountInfo, Ref, RefMut},
+    typhoon_program::{
+        bytes::{try_from_bytes, try_from_bytes_mut},
+        find_program_address,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        RawAccountInfo, Ref, RefMut,
+    },
 };
 
 pub trait ProgramId {
     const ID: Pubkey;
 }
 
+/// A set of programs sharing the same interface, like Token and Token-2022.
+pub trait ProgramIds {
+    const IDS: &'static [Pubkey];
+}
+
 pub trait Owner {
     const OWNER: Pubkey;
 }
 
+/// Data of an account owned by the current program, which is the only one allowed to debit it.
+/// Implemented by `#[account]` when neither `owner` nor `foreign` is given.
+pub trait ProgramOwned: Owner {}
+
+/// Data of an account which can be owned by any program of a set.
+pub trait InterfaceData: Pod + Discriminator {
+    const OWNERS: &'static [Pubkey];
+
+    /// Checks the whole data of an account owned by `owner` holds this type, initialized, and not
+    /// another one of the same programs.
+    fn is_valid(owner: &Pubkey, data: &[u8]) -> bool;
+}
+
+/// Data of an account stored at a single address of its owner, derived from `SEED`. Implemented
+/// by `#[account(singleton)]` with the name of the type as seed.
+pub trait SingletonData: Owner + AccountData {
+    const SEED: &'static [u8];
+
+    /// Address of the account and its bump, for the program and its clients.
+    fn find_address() -> (Pubkey, u8) {
+        find_program_address(&[Self::SEED], &Self::OWNER)
+    }
+}
+
+/// Config of an account signing for `threshold` of its `members`, read by `Multisig<T>`.
+pub trait MultisigData: Owner + AccountData {
+    fn threshold(&self) -> u8;
+    fn members(&self) -> &[Pubkey];
+}
+
 pub trait Discriminator {
     const DISCRIMINATOR: &'static [u8];
 }
 
+// Raw data, like the one of a `SystemAccount`
+impl Discriminator for [u8] {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+/// Version of the layout of an account declared with `#[account(version = N)]`, stored in its
+/// discriminator after the given prefix.
+pub trait Versioned: Discriminator {
+    const VERSION: u8;
+}
+
+/// Data of an account, read without copy from the bytes following its discriminator.
+pub trait AccountData: Discriminator {
+    /// Minimum length of the data, discriminator excluded.
+    const MIN_LEN: usize;
+
+    fn read(data: &[u8]) -> Option<&Self>;
+    fn read_mut(data: &mut [u8]) -> Option<&mut Self>;
+}
+
+impl<T> AccountData for T
+where
+    T: Pod + Discriminator,
+{
+    const MIN_LEN: usize = core::mem::size_of::<T>();
+
+    fn read(data: &[u8]) -> Option<&Self> {
+        try_from_bytes(data.get(..Self::MIN_LEN)?)
+    }
+
+    fn read_mut(data: &mut [u8]) -> Option<&mut Self> {
+        try_from_bytes_mut(data.get_mut(..Self::MIN_LEN)?)
+    }
+}
+
+/// Data made of a `Pod` header followed by as many `Pod` entries as the account length allows,
+/// implemented by `#[account]` for structs ending with a `#[tail]` slice and read with
+/// [`read_tail`].
+///
+/// # Safety
+///
+/// `Self` must be `#[repr(C)]`, made of the fields of `Header` followed by a slice of `Entry`,
+/// without padding after the header or between the entries.
+pub unsafe trait Tail: AccountData {
+    type Header: Pod;
+    type Entry: Pod;
+
+    fn tail(&self) -> &[Self::Entry];
+    fn tail_mut(&mut self) -> &mut [Self::Entry];
+
+    /// Pointer to the data starting at `data` and holding `len` entries, written
+    /// `core::ptr::slice_from_raw_parts(data, len) as *const Self`.
+    fn from_raw_parts(data: *const u8, len: usize) -> *const Self;
+
+    /// Length of an account holding `len` entries, discriminator included.
+    fn space(len: usize) -> usize {
+        Self::DISCRIMINATOR.len()
+            + core::mem::size_of::<Self::Header>()
+            + len * core::mem::size_of::<Self::Entry>()
+    }
+}
+
+/// Number of entries of the tail held by `data`, checking its length and its alignment.
+fn tail_len<T>(data: &[u8]) -> Option<usize>
+where
+    T: Tail + ?Sized,
+{
+    let align = core::mem::align_of::<T::Header>().max(core::mem::align_of::<T::Entry>());
+    if data.as_ptr().align_offset(align) != 0 {
+        return None;
+    }
+
+    let tail_len = data.len().checked_sub(core::mem::size_of::<T::Header>())?;
+    if tail_len % core::mem::size_of::<T::Entry>() != 0 {
+        return None;
+    }
+
+    Some(tail_len / core::mem::size_of::<T::Entry>())
+}
+
+/// Reads a [`Tail`] without copy, the `read` of its `AccountData`.
+pub fn read_tail<T>(data: &[u8]) -> Option<&T>
+where
+    T: Tail + ?Sized,
+{
+    let len = tail_len::<T>(data)?;
+    // SAFETY: the data is aligned and holds the header and `len` entries, laid out without
+    // padding as required by `Tail`, and the slice metadata is the number of entries.
+    Some(unsafe { &*T::from_raw_parts(data.as_ptr(), len) })
+}
+
+/// Mutably reads a [`Tail`] without copy, the `read_mut` of its `AccountData`.
+pub fn read_tail_mut<T>(data: &mut [u8]) -> Option<&mut T>
+where
+    T: Tail + ?Sized,
+{
+    let len = tail_len::<T>(data)?;
+    // SAFETY: same as `read_tail`, the data is borrowed mutably.
+    Some(unsafe { &mut *(T::from_raw_parts(data.as_mut_ptr(), len) as *mut T) })
+}
+
 pub trait ReadableAccount: AsRef<RawAccountInfo> {
     type DataType: ?Sized;
 
@@ -37,16 +182,83 @@ pub trait WritableAccount: ReadableAccount + Sealed {
 
 pub trait SignerAccount: ReadableAccount + Sealed {}
 
+/// An account type wrapping another one to add checks or methods, usually implemented with
+/// `#[derive(AccountWrapper)]`.
+///
+/// The wrapper is readable, writable or a signer like the account it wraps, so `T` can be
+/// `Mut<Account<'a, U>>` or `Signer<'a>` for example. The checks go in its `FromAccountInfo`
+/// implementation.
+pub trait AccountWrapper: AsRef<RawAccountInfo> {
+    type Inner: ReadableAccount;
+
+    fn inner(&self) -> &Self::Inner;
+}
+
+impl<T> ReadableAccount for T
+where
+    T: AccountWrapper,
+{
+    type DataType = <T::Inner as ReadableAccount>::DataType;
+
+    fn key(&self) -> &Pubkey {
+        self.inner().key()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.inner().owner()
+    }
+
+    fn lamports(&self) -> Result<Ref<u64>, ProgramError> {
+        self.inner().lamports()
+    }
+
+    fn data(&self) -> Result<Ref<Self::DataType>, ProgramError> {
+        self.inner().data()
+    }
+}
+
+impl<T> WritableAccount for T
+where
+    T: AccountWrapper,
+    T::Inner: WritableAccount,
+{
+    fn realloc(&self, new_len: usize, zero_init: bool) -> Result<(), ProgramError> {
+        self.inner().realloc(new_len, zero_init)
+    }
+
+    fn mut_lamports(&self) -> Result<RefMut<u64>, ProgramError> {
+        self.inner().mut_lamports()
+    }
+
+    fn mut_data(&self) -> Result<RefMut<Self::DataType>, ProgramError> {
+        self.inner().mut_data()
+    }
+}
+
+impl<T> SignerAccount for T
+where
+    T: AccountWrapper,
+    T::Inner: SignerAccount,
+{
+}
+
 mod sealed {
     use {
-        super::{Mut, ReadableAccount, Signer},
+        super::{AccountWrapper, Multisig, Mut, ReadableAccount, Signer},
         typhoon_program::RawAccountInfo,
     };
 
     pub trait Sealed {}
 
     impl<T> Sealed for Mut<T> where T: ReadableAccount + AsRef<RawAccountInfo> {}
-    impl Sealed for Signer<'_> {}
+    impl<T> Sealed for Signer<'_, T> where T: ReadableAccount {}
+    impl<T> Sealed for Multisig<'_, T> where T: ReadableAccount {}
+    impl<T> Sealed for T
+    where
+        T: AccountWrapper,
+        T::Inner: Sealed,
+    {
+    }
 }
 
 pub trait FromAccountInfo<'a>: Sized {
@@ -0,0 +1,163 @@
+//! Checks of the signatures verified by the Ed25519 and secp256k1 precompiles.
+//!
+//! Precompile instructions are verified before the transaction is executed, so finding one which
+//! covers the expected signer, message and signature is enough to trust them.
+
+use {
+    crate::{Ed25519Program, InstructionsSysvar, ProgramId, Secp256k1Program},
+    typhoon_errors::Error,
+    typhoon_program::{
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        secp256k1::{ETH_ADDRESS_LEN, SIGNATURE_LEN},
+    },
+};
+
+const ED25519_OFFSETS_START: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+/// Instruction index of data stored in the Ed25519 instruction itself.
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+const SECP256K1_OFFSETS_START: usize = 1;
+const SECP256K1_OFFSETS_LEN: usize = 11;
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+/// Reads `len` bytes at `offset` of the data of the instruction `index`, or of `own` when `index`
+/// is `None`.
+fn precompile_data<'a>(
+    instructions: &'a InstructionsSysvar,
+    own: &'a [u8],
+    index: Option<usize>,
+    offset: usize,
+    len: usize,
+) -> Result<&'a [u8], ProgramError> {
+    let data = match index {
+        Some(index) => instructions.load_instruction_at(index)?.data(),
+        None => own,
+    };
+
+    data.get(offset..offset + len)
+        .ok_or(ProgramError::InvalidInstructionData)
+}
+
+/// Ensures an Ed25519 precompile instruction of the transaction verified `signature` of `message`
+/// by `pubkey`.
+pub fn verify_ed25519_ix(
+    instructions: &InstructionsSysvar,
+    pubkey: &Pubkey,
+    message: &[u8],
+    signature: &[u8; 64],
+) -> Result<(), ProgramError> {
+    for index in 0..instructions.len()? {
+        let ix = instructions.load_instruction_at(index)?;
+        if ix.program_id() != &Ed25519Program::ID {
+            continue;
+        }
+
+        let data = ix.data();
+        let count = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+        for i in 0..count {
+            let start = ED25519_OFFSETS_START + i * ED25519_OFFSETS_LEN;
+            let offsets = data
+                .get(start..start + ED25519_OFFSETS_LEN)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            let field = |n: usize| read_u16(offsets, 2 * n);
+            let source = |n: usize| {
+                let index = field(n);
+                (index != ED25519_CURRENT_INSTRUCTION).then_some(index as usize)
+            };
+
+            if field(5) as usize != message.len() {
+                continue;
+            }
+
+            let found_signature =
+                precompile_data(instructions, data, source(1), field(0) as usize, 64)?;
+            let found_pubkey =
+                precompile_data(instructions, data, source(3), field(2) as usize, 32)?;
+            let found_message = precompile_data(
+                instructions,
+                data,
+                source(6),
+                field(4) as usize,
+                message.len(),
+            )?;
+
+            if found_signature == signature
+                && found_pubkey == pubkey.as_ref()
+                && found_message == message
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(Error::SignatureNotVerified.into())
+}
+
+/// Ensures a secp256k1 precompile instruction of the transaction verified `signature` of
+/// `message` by the Ethereum address `eth_address`.
+pub fn verify_secp256k1_ix(
+    instructions: &InstructionsSysvar,
+    eth_address: &[u8; ETH_ADDRESS_LEN],
+    message: &[u8],
+    signature: &[u8; SIGNATURE_LEN],
+    recovery_id: u8,
+) -> Result<(), ProgramError> {
+    for index in 0..instructions.len()? {
+        let ix = instructions.load_instruction_at(index)?;
+        if ix.program_id() != &Secp256k1Program::ID {
+            continue;
+        }
+
+        let data = ix.data();
+        let count = *data.first().ok_or(ProgramError::InvalidInstructionData)? as usize;
+
+        for i in 0..count {
+            let start = SECP256K1_OFFSETS_START + i * SECP256K1_OFFSETS_LEN;
+            let offsets = data
+                .get(start..start + SECP256K1_OFFSETS_LEN)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+
+            if read_u16(offsets, 8) as usize != message.len() {
+                continue;
+            }
+
+            let found_signature = precompile_data(
+                instructions,
+                data,
+                Some(offsets[2] as usize),
+                read_u16(offsets, 0) as usize,
+                SIGNATURE_LEN + 1,
+            )?;
+            let found_address = precompile_data(
+                instructions,
+                data,
+                Some(offsets[5] as usize),
+                read_u16(offsets, 3) as usize,
+                ETH_ADDRESS_LEN,
+            )?;
+            let found_message = precompile_data(
+                instructions,
+                data,
+                Some(offsets[10] as usize),
+                read_u16(offsets, 6) as usize,
+                message.len(),
+            )?;
+
+            if found_signature[..SIGNATURE_LEN] == signature[..]
+                && found_signature[SIGNATURE_LEN] == recovery_id
+                && found_address == eth_address
+                && found_message == message
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    Err(Error::SignatureNotVerified.into())
+}
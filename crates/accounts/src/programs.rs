@@ -1,7 +1,95 @@
-use {crate::ProgramId, typhoon_program::system_program};
+use {
+    crate::{ProgramId, ProgramIds},
+    typhoon_program::{find_program_address, pubkey::Pubkey, pubkey_from_array, system_program},
+};
 
 pub struct System;
 
 impl ProgramId for System {
-    const ID: typhoon_program::pubkey::Pubkey = system_program::ID;
+    const ID: Pubkey = system_program::ID;
+}
+
+/// `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`
+pub struct TokenProgram;
+
+impl ProgramId for TokenProgram {
+    const ID: Pubkey = pubkey_from_array([
+        6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180, 133,
+        237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+    ]);
+}
+
+/// `TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb`
+pub struct Token2022Program;
+
+impl ProgramId for Token2022Program {
+    const ID: Pubkey = pubkey_from_array([
+        6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252,
+        77, 131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+    ]);
+}
+
+/// Either the Token or the Token-2022 program.
+pub struct TokenInterface;
+
+impl ProgramIds for TokenInterface {
+    const IDS: &'static [Pubkey] = &[TokenProgram::ID, Token2022Program::ID];
+}
+
+/// `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`
+pub struct AssociatedTokenProgram;
+
+impl ProgramId for AssociatedTokenProgram {
+    const ID: Pubkey = pubkey_from_array([
+        140, 151, 37, 143, 78, 36, 137, 241, 187, 61, 16, 41, 20, 142, 13, 131, 11, 90, 19, 153,
+        218, 255, 16, 132, 4, 142, 123, 216, 219, 233, 248, 89,
+    ]);
+}
+
+impl AssociatedTokenProgram {
+    /// Derives the associated token account of `wallet` for `mint`, also usable off-chain to
+    /// build the instructions.
+    pub fn find_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> (Pubkey, u8) {
+        find_program_address(
+            &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+            &Self::ID,
+        )
+    }
+}
+
+/// `BPFLoaderUpgradeab1e11111111111111111111111`
+pub struct BpfLoaderUpgradeable;
+
+impl ProgramId for BpfLoaderUpgradeable {
+    const ID: Pubkey = pubkey_from_array([
+        2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61,
+        22, 193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+    ]);
+}
+
+impl BpfLoaderUpgradeable {
+    /// Derives the `ProgramData` account of `program_id`, also usable off-chain.
+    pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+        find_program_address(&[program_id.as_ref()], &Self::ID)
+    }
+}
+
+/// `Ed25519SigVerify111111111111111111111111111`
+pub struct Ed25519Program;
+
+impl ProgramId for Ed25519Program {
+    const ID: Pubkey = pubkey_from_array([
+        3, 125, 70, 214, 124, 147, 251, 190, 18, 249, 66, 143, 131, 141, 64, 255, 5, 112, 116, 73,
+        39, 244, 138, 100, 252, 202, 112, 68, 128, 0, 0, 0,
+    ]);
+}
+
+/// `KeccakSecp256k11111111111111111111111111111`
+pub struct Secp256k1Program;
+
+impl ProgramId for Secp256k1Program {
+    const ID: Pubkey = pubkey_from_array([
+        4, 198, 252, 32, 240, 80, 204, 240, 85, 132, 215, 33, 28, 159, 140, 245, 158, 193, 71, 133,
+        187, 22, 106, 30, 40, 48, 232, 18, 32, 0, 0, 0,
+    ]);
 }
@@ -1,4 +1,4 @@
-use {crate::Discriminator, bytemuck::Pod, typhoon_program::bytes::try_from_bytes_mut};
+use crate::AccountData;
 
 pub trait ReadMut {
     fn read_mut(data: &mut [u8]) -> Option<&mut Self>;
@@ -12,10 +12,9 @@ impl ReadMut for [u8] {
 
 impl<T> ReadMut for T
 where
-    T: Pod + Discriminator,
+    T: AccountData + ?Sized,
 {
     fn read_mut(data: &mut [u8]) -> Option<&mut Self> {
-        let dis_len = T::DISCRIMINATOR.len();
-        try_from_bytes_mut(&mut data[dis_len..std::mem::size_of::<Self>() + dis_len])
+        AccountData::read_mut(data.get_mut(T::DISCRIMINATOR.len()..)?)
     }
 }
@@ -0,0 +1,87 @@
+use typhoon_accounts::{
+    InterfaceData, MintData, ProgramId, Token2022Program, TokenAccountData, TokenProgram,
+};
+
+const STATE_OFFSET: usize = 108;
+const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+const MULTISIG_LEN: usize = 355;
+
+fn token_account(len: usize, state: u8) -> Vec<u8> {
+    let mut data = vec![0; len];
+    data[STATE_OFFSET] = state;
+    if len > ACCOUNT_TYPE_OFFSET {
+        data[ACCOUNT_TYPE_OFFSET] = 2;
+    }
+    data
+}
+
+fn mint(len: usize, is_initialized: u8) -> Vec<u8> {
+    let mut data = vec![0; len];
+    data[MINT_IS_INITIALIZED_OFFSET] = is_initialized;
+    if len > ACCOUNT_TYPE_OFFSET {
+        data[ACCOUNT_TYPE_OFFSET] = 1;
+    }
+    data
+}
+
+#[test]
+fn token_account_validity() {
+    let token = &TokenProgram::ID;
+    let token_2022 = &Token2022Program::ID;
+
+    assert!(TokenAccountData::is_valid(token, &token_account(165, 1)));
+    assert!(TokenAccountData::is_valid(
+        token_2022,
+        &token_account(165, 1)
+    ));
+    assert!(TokenAccountData::is_valid(
+        token_2022,
+        &token_account(170, 1)
+    ));
+
+    // Not initialized
+    assert!(!TokenAccountData::is_valid(token, &token_account(165, 0)));
+    assert!(!TokenAccountData::is_valid(
+        token_2022,
+        &token_account(170, 0)
+    ));
+
+    // Only Token-2022 accounts have extensions
+    assert!(!TokenAccountData::is_valid(token, &token_account(170, 1)));
+
+    // A multisig with the account type byte of an account
+    assert!(!TokenAccountData::is_valid(
+        token,
+        &token_account(MULTISIG_LEN, 1)
+    ));
+    assert!(!TokenAccountData::is_valid(
+        token_2022,
+        &token_account(MULTISIG_LEN, 1)
+    ));
+
+    // A mint with extensions
+    assert!(!TokenAccountData::is_valid(token_2022, &mint(170, 1)));
+}
+
+#[test]
+fn mint_validity() {
+    let token = &TokenProgram::ID;
+    let token_2022 = &Token2022Program::ID;
+
+    assert!(MintData::is_valid(token, &mint(82, 1)));
+    assert!(MintData::is_valid(token_2022, &mint(170, 1)));
+
+    // Not initialized
+    assert!(!MintData::is_valid(token, &mint(82, 0)));
+    assert!(!MintData::is_valid(token_2022, &mint(170, 0)));
+
+    // Only Token-2022 mints have extensions
+    assert!(!MintData::is_valid(token, &mint(170, 1)));
+
+    // A multisig with the account type byte of a mint
+    assert!(!MintData::is_valid(token_2022, &mint(MULTISIG_LEN, 1)));
+
+    // An account with extensions
+    assert!(!MintData::is_valid(token_2022, &token_account(170, 1)));
+}
@@ -1,8 +1,11 @@
 use {
     crate::constraints::Constraints,
     proc_macro2::{Span, TokenStream},
-    quote::{quote, ToTokens},
-    syn::{spanned::Spanned, visit_mut::VisitMut, Field, Ident, PathSegment, Type, TypePath},
+    quote::{quote, quote_spanned, ToTokens},
+    syn::{
+        spanned::Spanned, visit_mut::VisitMut, Field, GenericArgument, Ident, PathArguments,
+        PathSegment, Type, TypePath,
+    },
 };
 
 pub struct Account {
@@ -17,6 +20,19 @@ impl TryFrom<&mut Field> for Account {
     fn try_from(value: &mut Field) -> Result<Self, Self::Error> {
         let mut constraints = Constraints::default();
         constraints.visit_attributes_mut(&mut value.attrs);
+        if let Some(error) = constraints.take_error() {
+            return Err(error);
+        }
+
+        // The associated token address is derived from both of them
+        if constraints.get_associated_token_mint().is_some()
+            != constraints.get_associated_token_authority().is_some()
+        {
+            return Err(syn::Error::new(
+                value.ident.span(),
+                "associated_token::mint and associated_token::authority have to be set together",
+            ));
+        }
 
         let segment = match &value.ty {
             Type::Path(TypePath { path, .. }) => path.segments.last(),
@@ -50,29 +66,347 @@ impl ToTokens for NameList<'_> {
     }
 }
 
-pub struct Assign<'a>(Vec<(&'a Ident, &'a PathSegment, &'a Constraints)>);
+pub struct Assign<'a> {
+    context: &'a Ident,
+    accounts: Vec<(&'a Ident, &'a PathSegment, &'a Constraints)>,
+}
+
+impl Assign<'_> {
+    fn error_context(&self, name: &Ident) -> TokenStream {
+        if cfg!(feature = "logging") {
+            let context = self.context.to_string();
+            let field = name.to_string();
+
+            quote! {
+                .map_err(|err| {
+                    log_account_error(#context, #field, #name.key());
+                    err
+                })
+            }
+        } else {
+            quote!()
+        }
+    }
+
+    /// Finds the index of the first account of the context typed `#wrapper<#program>`.
+    fn find_program(&self, candidates: &[(&str, &str)]) -> Option<usize> {
+        self.accounts.iter().position(|(_, ty, _)| {
+            let PathArguments::AngleBracketed(args) = &ty.arguments else {
+                return false;
+            };
+
+            candidates.iter().any(|(wrapper, program)| {
+                ty.ident == wrapper
+                    && args.args.iter().any(|arg| {
+                        matches!(arg, GenericArgument::Type(Type::Path(TypePath { path, .. }))
+                            if path.segments.last().is_some_and(|seg| seg.ident == program))
+                    })
+            })
+        })
+    }
+
+    /// Expression creating and initializing the account, evaluating to the converted account.
+    fn init(&self, name: &Ident, ty: &PathSegment, c: &Constraints) -> TokenStream {
+        let error_context = self.error_context(name);
+
+        let Some(payer) = c.get_payer() else {
+            return syn::Error::new(name.span(), "Not found payer for the init constraint")
+                .to_compile_error();
+        };
+
+        if let Some(mint) = c.get_associated_token_mint() {
+            let Some(authority) = c.get_associated_token_authority() else {
+                return syn::Error::new(
+                    name.span(),
+                    "Not found associated_token::authority for the init constraint",
+                )
+                .to_compile_error();
+            };
+
+            let (Some(system_program), Some(token_program), Some(_)) = (
+                self.find_program(&[("Program", "System")]),
+                self.find_program(&[("Program", "TokenProgram"), ("Interface", "TokenInterface")]),
+                self.find_program(&[("Program", "AssociatedTokenProgram")]),
+            ) else {
+                return syn::Error::new(
+                    name.span(),
+                    "The associated token init needs a Program<System>, a Program<TokenProgram> or an Interface<TokenInterface>, and a Program<AssociatedTokenProgram> in the context",
+                )
+                .to_compile_error();
+            };
+
+            quote! {
+                {
+                    let system_acc = <Mut<SystemAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    AssociatedTokenCpi::create_associated_token_account(
+                        &system_acc,
+                        &#payer,
+                        &#authority,
+                        &#mint,
+                        &<UncheckedAccount as FromAccountInfo>::try_from_info(&accounts[#system_program])#error_context?,
+                        &<UncheckedAccount as FromAccountInfo>::try_from_info(&accounts[#token_program])#error_context?,
+                        false,
+                    )#error_context?;
+                    Mut::try_from_info(#name)#error_context?
+                }
+            }
+        } else if let Some(mint) = c.get_token_mint() {
+            let Some(authority) = c.get_token_authority() else {
+                return syn::Error::new(
+                    name.span(),
+                    "Not found token::authority for the init constraint",
+                )
+                .to_compile_error();
+            };
+
+            // Token-2022 accounts are sized by their extensions, unknown here
+            if !wraps(ty, "TokenAccount")
+                || self.find_program(&[("Program", "TokenProgram")]).is_none()
+            {
+                return syn::Error::new(
+                    name.span(),
+                    "The token init only creates accounts of the Token program: it needs a TokenAccount and a Program<TokenProgram> in the context",
+                )
+                .to_compile_error();
+            }
+
+            quote! {
+                {
+                    let system_acc = <Mut<SystemAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    SystemCpi::create_account(&system_acc, &#payer, &<TokenProgram as ProgramId>::ID, TokenAccountData::LEN as u64, None)#error_context?;
+                    // Only readable as a token account once initialized
+                    let unchecked_acc = <Mut<UncheckedAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    TokenCpi::initialize_account(&unchecked_acc, &#mint, #authority.key())#error_context?;
+                    Mut::try_from_info(#name)#error_context?
+                }
+            }
+        } else if c.get_mint_decimals().is_some() || c.get_mint_authority().is_some() {
+            let (Some(decimals), Some(authority)) = (c.get_mint_decimals(), c.get_mint_authority())
+            else {
+                return syn::Error::new(
+                    name.span(),
+                    "Not found mint::decimals or mint::authority for the init constraint",
+                )
+                .to_compile_error();
+            };
+
+            if !wraps(ty, "Mint") || self.find_program(&[("Program", "TokenProgram")]).is_none() {
+                return syn::Error::new(
+                    name.span(),
+                    "The mint init only creates mints of the Token program: it needs a Mint and a Program<TokenProgram> in the context",
+                )
+                .to_compile_error();
+            }
+
+            quote! {
+                {
+                    let system_acc = <Mut<SystemAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    SystemCpi::create_account(&system_acc, &#payer, &<TokenProgram as ProgramId>::ID, MintData::LEN as u64, None)#error_context?;
+                    let unchecked_acc = <Mut<UncheckedAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    TokenCpi::initialize_mint(&unchecked_acc, #decimals, #authority.key(), None)#error_context?;
+                    Mut::try_from_info(#name)#error_context?
+                }
+            }
+        } else {
+            let Some(space) = c.get_space() else {
+                return syn::Error::new(
+                    name.span(),
+                    "Not found payer or space for the init constraint",
+                )
+                .to_compile_error();
+            };
+
+            // The address of a singleton is derived from its seed, signing its creation
+            let seeds = match singleton_data(ty) {
+                Some(data) => quote! {
+                    Some(&[program::signer!(
+                        <#data as SingletonData>::SEED,
+                        &[<#data as SingletonData>::find_address().1]
+                    )])
+                },
+                None => quote!(None),
+            };
+
+            quote! {
+                {
+                    let system_acc = <Mut<SystemAccount> as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    SystemCpi::create_account(&system_acc, &#payer, &crate::ID, #space as u64, #seeds)#error_context?;
+                    let discriminator = <<#ty as ReadableAccount>::DataType as Discriminator>::DISCRIMINATOR;
+                    system_acc.mut_data()#error_context?[..discriminator.len()].copy_from_slice(discriminator);
+                    Mut::try_from_info(#name)#error_context?
+                }
+            }
+        }
+    }
+
+    /// Converts the data of an account written with the `migrate` layout before loading it.
+    fn migrate(&self, name: &Ident, ty: &PathSegment, c: &Constraints) -> TokenStream {
+        let Some(from) = c.get_migrate() else {
+            return quote!();
+        };
+
+        let Some(payer) = c.get_payer() else {
+            return syn::Error::new(name.span(), "Not found payer for the migrate constraint")
+                .to_compile_error();
+        };
+
+        let error_context = self.error_context(name);
+
+        quote! {
+            <<#ty as ReadableAccount>::DataType as Migrate<#from>>::upgrade(#name, &#payer)#error_context?;
+        }
+    }
+
+    /// Checks the `token::*`, `mint::*` and `associated_token::*` constraints of an existing
+    /// account.
+    fn token_checks(&self, name: &Ident, c: &Constraints) -> TokenStream {
+        let error_context = self.error_context(name);
+        let mut checks = Vec::new();
+
+        if let Some(mint) = c.get_token_mint().or(c.get_associated_token_mint()) {
+            checks.push((
+                quote!(data.mint() != #mint.key()),
+                quote!(TokenMintMismatch),
+            ));
+        }
+
+        if let Some(authority) = c
+            .get_token_authority()
+            .or(c.get_associated_token_authority())
+        {
+            checks.push((
+                quote!(data.owner() != #authority.key()),
+                quote!(TokenOwnerMismatch),
+            ));
+        }
+
+        if let Some(decimals) = c.get_mint_decimals() {
+            checks.push((
+                quote!(data.decimals() != #decimals),
+                quote!(MintDecimalsMismatch),
+            ));
+        }
+
+        if let Some(authority) = c.get_mint_authority() {
+            checks.push((
+                quote!(data.mint_authority() != Some(#authority.key())),
+                quote!(MintAuthorityMismatch),
+            ));
+        }
+
+        if let (Some(mint), Some(authority)) = (
+            c.get_associated_token_mint(),
+            c.get_associated_token_authority(),
+        ) {
+            checks.push((
+                quote!(#name.key() != &AssociatedTokenProgram::find_address(#authority.key(), #mint.key(), #name.owner()).0),
+                quote!(AssociatedTokenAddressMismatch),
+            ));
+        }
+
+        if checks.is_empty() {
+            return quote!();
+        }
+
+        let checks = checks.into_iter().map(|(condition, error)| {
+            quote! {
+                if #condition {
+                    return Err(ProgramError::from(Error::#error))#error_context;
+                }
+            }
+        });
+
+        quote! {
+            {
+                let data = #name.data()#error_context?;
+                #(#checks)*
+            }
+        }
+    }
+
+    /// Checks the `upgrade_authority` constraint of a `ProgramData` account, the authority being a
+    /// signer.
+    fn upgrade_authority_check(&self, name: &Ident, c: &Constraints) -> TokenStream {
+        let Some(authority) = c.get_upgrade_authority() else {
+            return quote!();
+        };
+
+        let error_context = self.error_context(name);
+
+        // The authority has to sign, not only to be given
+        let assert_signer = quote_spanned!(authority.span()=> assert_signer(&#authority););
+
+        quote! {
+            {
+                fn assert_signer<T: SignerAccount>(_: &T) {}
+                #assert_signer
+            }
+
+            if #name.upgrade_authority_address()#error_context? != Some(*#authority.key()) {
+                return Err(ProgramError::from(Error::UpgradeAuthorityMismatch))#error_context;
+            }
+        }
+    }
+
+    /// Checks the `pausable` constraint of an account whose data is `Pausable`, like the
+    /// `RoleRegistry` of the program.
+    fn pausable_check(&self, name: &Ident, c: &Constraints) -> TokenStream {
+        if !c.has_pausable() {
+            return quote!();
+        }
+
+        let error_context = self.error_context(name);
+
+        quote! {
+            if Pausable::is_paused(&*#name.data()#error_context?) {
+                return Err(ProgramError::from(Error::ProgramPaused))#error_context;
+            }
+        }
+    }
+}
 
 impl ToTokens for Assign<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let assign_fields = self.0.iter().map(|(name, ty, c)| {
+        let assign_fields = self.accounts.iter().map(|(name, ty, c)| {
+            let error_context = self.error_context(name);
+
             if c.has_init() {
-                let payer = c.get_payer();
-                let space = c.get_space();
+                let init = self.init(name, ty, c);
 
-                let (Some(payer), Some(space)) = (payer, space) else {
-                    return syn::Error::new(name.span(), "Not found payer or space for the init constraint").to_compile_error()
-                };
+                quote! {
+                    let #name: #ty = #init;
+                }
+            } else if c.has_init_if_needed() {
+                let init = self.init(name, ty, c);
+                let migrate = self.migrate(name, ty, c);
+                let checks = self.token_checks(name, c);
+                let upgrade_authority = self.upgrade_authority_check(name, c);
+                let pausable = self.pausable_check(name, c);
 
                 quote! {
-                    let #name: #ty = {
-                        let system_acc = <Mut<SystemAccount> as FromAccountInfo>::try_from_info(#name)?;
-                        SystemCpi::create_account(&system_acc, &#payer, &crate::ID, #space as u64, None)?;
-                        Mut::try_from_info(#name)?
+                    let #name: #ty = if #name.owner() == &<System as ProgramId>::ID {
+                        #init
+                    } else {
+                        #migrate
+                        let #name = <#ty as FromAccountInfo>::try_from_info(#name)#error_context?;
+                        #checks
+                        #upgrade_authority
+                        #pausable
+                        #name
                     };
                 }
             } else {
+                let migrate = self.migrate(name, ty, c);
+                let checks = self.token_checks(name, c);
+                let upgrade_authority = self.upgrade_authority_check(name, c);
+                let pausable = self.pausable_check(name, c);
+
                 quote! {
-                    let #name = <#ty as FromAccountInfo>::try_from_info(#name)?;
+                    #migrate
+                    let #name = <#ty as FromAccountInfo>::try_from_info(#name)#error_context?;
+                    #checks
+                    #upgrade_authority
+                    #pausable
                 }
             }
         });
@@ -85,16 +419,53 @@ impl ToTokens for Assign<'_> {
     }
 }
 
+/// Whether the account is an `account`, possibly wrapped in `Mut` or `Signer`.
+fn wraps(ty: &PathSegment, account: &str) -> bool {
+    if ty.ident == account {
+        return true;
+    }
+
+    let PathArguments::AngleBracketed(ref args) = ty.arguments else {
+        return false;
+    };
+
+    args.args.iter().any(|arg| {
+        matches!(arg, GenericArgument::Type(Type::Path(TypePath { path, .. }))
+            if path.segments.last().is_some_and(|seg| wraps(seg, account)))
+    })
+}
+
+/// Finds the data type of a `Singleton<T>`, possibly wrapped in `Mut` or `Signer`.
+fn singleton_data(ty: &PathSegment) -> Option<&Type> {
+    let PathArguments::AngleBracketed(ref args) = ty.arguments else {
+        return None;
+    };
+
+    args.args.iter().rev().find_map(|arg| match arg {
+        GenericArgument::Type(data) if ty.ident == "Singleton" => Some(data),
+        GenericArgument::Type(Type::Path(TypePath { path, .. })) => {
+            singleton_data(path.segments.last()?)
+        }
+        _ => None,
+    })
+}
+
 pub struct Accounts(pub Vec<Account>);
 
 impl Accounts {
-    pub fn split_for_impl(&self) -> (NameList, Assign) {
+    pub fn split_for_impl<'a>(&'a self, context: &'a Ident) -> (NameList<'a>, Assign<'a>) {
         let (name_list, assign): (Vec<&Ident>, Vec<(&Ident, &PathSegment, &Constraints)>) = self
             .0
             .iter()
             .map(|el| (&el.name, (&el.name, &el.ty, &el.constraints)))
             .unzip();
 
-        (NameList(name_list), Assign(assign))
+        (
+            NameList(name_list),
+            Assign {
+                context,
+                accounts: assign,
+            },
+        )
     }
 }
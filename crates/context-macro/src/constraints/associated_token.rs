@@ -0,0 +1,30 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+pub struct ConstraintAssociatedTokenMint {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintAssociatedTokenMint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintAssociatedTokenMint { target })
+    }
+}
+
+pub struct ConstraintAssociatedTokenAuthority {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintAssociatedTokenAuthority {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintAssociatedTokenAuthority { target })
+    }
+}
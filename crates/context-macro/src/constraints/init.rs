@@ -1 +1,3 @@
 pub struct ConstraintInit;
+
+pub struct ConstraintInitIfNeeded;
@@ -0,0 +1,17 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Token, Type,
+};
+
+pub struct ConstraintMigrate {
+    pub from: Type,
+}
+
+impl Parse for ConstraintMigrate {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let from = input.parse()?;
+
+        Ok(ConstraintMigrate { from })
+    }
+}
@@ -0,0 +1,30 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+pub struct ConstraintMintDecimals {
+    pub decimals: Expr,
+}
+
+impl Parse for ConstraintMintDecimals {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let decimals = input.parse()?;
+
+        Ok(ConstraintMintDecimals { decimals })
+    }
+}
+
+pub struct ConstraintMintAuthority {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintMintAuthority {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintMintAuthority { target })
+    }
+}
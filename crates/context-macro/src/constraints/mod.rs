@@ -1,24 +1,43 @@
 use syn::{
     parse::{Parse, ParseStream},
     visit_mut::VisitMut,
-    Expr, Ident, Token,
+    Expr, Ident, Token, Type,
 };
 
+mod associated_token;
 mod init;
+mod migrate;
+mod mint;
+mod pausable;
 mod payer;
 mod space;
+mod token;
+mod upgrade_authority;
 
-use {init::*, payer::*, space::*};
+use {
+    associated_token::*, init::*, migrate::*, mint::*, pausable::*, payer::*, space::*, token::*,
+    upgrade_authority::*,
+};
 
 //TODO rewrite it to add custom constraint for users
 pub enum Constraint {
     Init(ConstraintInit),
+    InitIfNeeded(ConstraintInitIfNeeded),
+    Migrate(ConstraintMigrate),
     Payer(ConstraintPayer),
     Space(ConstraintSpace),
+    TokenMint(ConstraintTokenMint),
+    TokenAuthority(ConstraintTokenAuthority),
+    MintDecimals(ConstraintMintDecimals),
+    MintAuthority(ConstraintMintAuthority),
+    AssociatedTokenMint(ConstraintAssociatedTokenMint),
+    AssociatedTokenAuthority(ConstraintAssociatedTokenAuthority),
+    UpgradeAuthority(ConstraintUpgradeAuthority),
+    Pausable(ConstraintPausable),
 }
 
 #[derive(Default)]
-pub struct Constraints(Vec<Constraint>);
+pub struct Constraints(Vec<Constraint>, Option<syn::Error>);
 
 impl VisitMut for Constraints {
     fn visit_attributes_mut(&mut self, attrs: &mut Vec<syn::Attribute>) {
@@ -29,8 +48,12 @@ impl VisitMut for Constraints {
                 return true;
             }
 
-            if let Ok(mut constraints) = attr.parse_args_with(parse_constraints) {
-                self.0.append(&mut constraints);
+            match attr.parse_args_with(parse_constraints) {
+                Ok(mut constraints) => self.0.append(&mut constraints),
+                Err(err) => match &mut self.1 {
+                    Some(error) => error.combine(err),
+                    None => self.1 = Some(err),
+                },
             }
 
             false
@@ -39,10 +62,35 @@ impl VisitMut for Constraints {
 }
 
 impl Constraints {
+    /// Takes the errors of the `#[constraint]` attributes that failed to parse.
+    pub fn take_error(&mut self) -> Option<syn::Error> {
+        self.1.take()
+    }
+
     pub fn has_init(&self) -> bool {
         self.0.iter().any(|c| matches!(&c, Constraint::Init(_)))
     }
 
+    pub fn has_init_if_needed(&self) -> bool {
+        self.0
+            .iter()
+            .any(|c| matches!(&c, Constraint::InitIfNeeded(_)))
+    }
+
+    pub fn has_pausable(&self) -> bool {
+        self.0.iter().any(|c| matches!(&c, Constraint::Pausable(_)))
+    }
+
+    pub fn get_migrate(&self) -> Option<&Type> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::Migrate(ConstraintMigrate { from }) = c {
+                Some(from)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn get_payer(&self) -> Option<&Expr> {
         self.0.iter().find_map(|c| {
             if let Constraint::Payer(ConstraintPayer { target }) = c {
@@ -62,24 +110,145 @@ impl Constraints {
             }
         })
     }
+
+    pub fn get_token_mint(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::TokenMint(ConstraintTokenMint { target }) = c {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_token_authority(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::TokenAuthority(ConstraintTokenAuthority { target }) = c {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_mint_decimals(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::MintDecimals(ConstraintMintDecimals { decimals }) = c {
+                Some(decimals)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_mint_authority(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::MintAuthority(ConstraintMintAuthority { target }) = c {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_associated_token_mint(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::AssociatedTokenMint(ConstraintAssociatedTokenMint { target }) = c {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_associated_token_authority(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::AssociatedTokenAuthority(ConstraintAssociatedTokenAuthority {
+                target,
+            }) = c
+            {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_upgrade_authority(&self) -> Option<&Expr> {
+        self.0.iter().find_map(|c| {
+            if let Constraint::UpgradeAuthority(ConstraintUpgradeAuthority { target }) = c {
+                Some(target)
+            } else {
+                None
+            }
+        })
+    }
 }
 
 pub fn parse_constraints(input: ParseStream) -> syn::Result<Vec<Constraint>> {
     let mut constraints = Vec::new();
 
     while !input.is_empty() {
-        let name = input.parse::<Ident>()?.to_string();
+        let ident = input.parse::<Ident>()?;
+        let mut name = ident.to_string();
+        if input.peek(Token![::]) {
+            let _punct: Token![::] = input.parse()?;
+            name = format!("{name}::{}", input.parse::<Ident>()?);
+        }
+
         match name.as_str() {
             "init" => {
                 constraints.push(Constraint::Init(ConstraintInit));
             }
+            "init_if_needed" => {
+                constraints.push(Constraint::InitIfNeeded(ConstraintInitIfNeeded));
+            }
+            "migrate" => {
+                constraints.push(Constraint::Migrate(ConstraintMigrate::parse(input)?));
+            }
             "payer" => {
                 constraints.push(Constraint::Payer(ConstraintPayer::parse(input)?));
             }
             "space" => {
                 constraints.push(Constraint::Space(ConstraintSpace::parse(input)?));
             }
-            _ => return Err(syn::Error::new(input.span(), "Unknow constraint.")),
+            "token::mint" => {
+                constraints.push(Constraint::TokenMint(ConstraintTokenMint::parse(input)?));
+            }
+            "token::authority" => {
+                constraints.push(Constraint::TokenAuthority(ConstraintTokenAuthority::parse(
+                    input,
+                )?));
+            }
+            "mint::decimals" => {
+                constraints.push(Constraint::MintDecimals(ConstraintMintDecimals::parse(
+                    input,
+                )?));
+            }
+            "mint::authority" => {
+                constraints.push(Constraint::MintAuthority(ConstraintMintAuthority::parse(
+                    input,
+                )?));
+            }
+            "associated_token::mint" => {
+                constraints.push(Constraint::AssociatedTokenMint(
+                    ConstraintAssociatedTokenMint::parse(input)?,
+                ));
+            }
+            "associated_token::authority" => {
+                constraints.push(Constraint::AssociatedTokenAuthority(
+                    ConstraintAssociatedTokenAuthority::parse(input)?,
+                ));
+            }
+            "upgrade_authority" => {
+                constraints.push(Constraint::UpgradeAuthority(
+                    ConstraintUpgradeAuthority::parse(input)?,
+                ));
+            }
+            "pausable" => {
+                constraints.push(Constraint::Pausable(ConstraintPausable));
+            }
+            _ => return Err(syn::Error::new(ident.span(), "Unknown constraint.")),
         }
 
         if input.peek(Token![,]) {
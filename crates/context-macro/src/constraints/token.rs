@@ -0,0 +1,30 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+pub struct ConstraintTokenMint {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintTokenMint {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintTokenMint { target })
+    }
+}
+
+pub struct ConstraintTokenAuthority {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintTokenAuthority {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintTokenAuthority { target })
+    }
+}
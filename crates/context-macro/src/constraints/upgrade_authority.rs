@@ -0,0 +1,17 @@
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Token,
+};
+
+pub struct ConstraintUpgradeAuthority {
+    pub target: Expr,
+}
+
+impl Parse for ConstraintUpgradeAuthority {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let _punct: Token![=] = input.parse()?;
+        let target = input.parse()?;
+
+        Ok(ConstraintUpgradeAuthority { target })
+    }
+}
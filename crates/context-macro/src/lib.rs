@@ -89,7 +89,7 @@ impl ToTokens for Context {
 
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let new_lifetime: Lifetime = parse_quote!('info);
-        let (name_list, accounts_assign) = self.accounts.split_for_impl();
+        let (name_list, accounts_assign) = self.accounts.split_for_impl(name);
         let (args_struct_name, args_struct, args_assign) = self.args.split_for_impl(name);
 
         if let Item::Struct(account_struct) = account_struct {
@@ -1,4 +1,21 @@
-use syn::{parse_quote, visit_mut::VisitMut, PathArguments};
+use syn::{parse_quote, visit_mut::VisitMut, GenericArgument, PathArguments};
+
+/// Accounts of the framework whose generic arguments are programs, sysvars or data types instead
+/// of accounts, so no lifetime is injected in them.
+///
+/// Other types are assumed to wrap accounts, like `Signer<T>` or the ones deriving
+/// `AccountWrapper`. A type written with its lifetime, like `Capped<'info, Counter>`, is left as
+/// is: it's how a wrapper with marker arguments is used in a context.
+const ACCOUNTS_WITH_MARKERS: &[&str] = &[
+    "Account",
+    "AnchorAccount",
+    "Program",
+    "ProgramData",
+    "Interface",
+    "InterfaceAccount",
+    "Singleton",
+    "Sysvar",
+];
 
 pub struct InjectLifetime;
 
@@ -16,6 +33,11 @@ impl VisitMut for InjectLifetime {
             }
 
             match seg.arguments {
+                PathArguments::AngleBracketed(ref gen_args)
+                    if matches!(gen_args.args.first(), Some(GenericArgument::Lifetime(_))) =>
+                {
+                    return;
+                }
                 PathArguments::AngleBracketed(ref mut gen_args) => {
                     gen_args.args.insert(0, parse_quote!('info));
                 }
@@ -24,6 +46,11 @@ impl VisitMut for InjectLifetime {
                 }
                 PathArguments::Parenthesized(_) => {}
             }
+
+            // Wrappers like `Signer<T>` or the ones deriving `AccountWrapper` contain accounts.
+            if !ACCOUNTS_WITH_MARKERS.iter().any(|name| seg.ident == name) {
+                self.visit_path_segment_mut(seg);
+            }
         }
     }
 }
@@ -1,16 +1,18 @@
 use {
     crate::HandlerContext,
     bytemuck::Pod,
-    std::ops::Deref,
-    typhoon_program::{bytes::try_from_bytes, program_error::ProgramError, RawAccountInfo},
+    std::{marker::PhantomData, ops::Deref},
+    typhoon_program::{program_error::ProgramError, RawAccountInfo},
 };
 
+/// Arguments read from the instruction data. They are copied since the instruction data, following
+/// the one byte discriminator of the handler, isn't aligned.
 #[derive(Debug)]
-pub struct Args<'a, T>(&'a T);
+pub struct Args<'a, T>(T, PhantomData<&'a [u8]>);
 
-impl<'a, T> Args<'a, T> {
-    pub fn new(arg: &'a T) -> Self {
-        Args(arg)
+impl<T> Args<'_, T> {
+    pub fn new(arg: T) -> Self {
+        Args(arg, PhantomData)
     }
 }
 
@@ -18,7 +20,7 @@ impl<T> Deref for Args<'_, T> {
     type Target = T;
 
     fn deref(&self) -> &Self::Target {
-        self.0
+        &self.0
     }
 }
 
@@ -30,8 +32,10 @@ where
         _accounts: &mut &'a [RawAccountInfo],
         instruction_data: &mut &'a [u8],
     ) -> Result<Self, ProgramError> {
-        let arg: &T = try_from_bytes(&instruction_data[..std::mem::size_of::<T>()])
+        let bytes = instruction_data
+            .get(..std::mem::size_of::<T>())
             .ok_or(ProgramError::InvalidInstructionData)?;
+        let arg: T = bytemuck::pod_read_unaligned(bytes);
 
         let (_, remaining) = instruction_data.split_at(std::mem::size_of::<T>());
         *instruction_data = remaining;
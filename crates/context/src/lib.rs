@@ -4,6 +4,7 @@ use {
 };
 
 mod args;
+mod multisig;
 mod remaining_accounts;
 
 pub use args::*;
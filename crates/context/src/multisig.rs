@@ -0,0 +1,27 @@
+use {
+    crate::HandlerContext,
+    typhoon_accounts::{FromAccountInfo, Multisig, MultisigData, ReadableAccount},
+    typhoon_program::{program_error::ProgramError, RawAccountInfo},
+};
+
+/// Takes the multisig account and all the remaining accounts as its signers, so it must be the
+/// last parameter of the handler.
+impl<'a, T> HandlerContext<'a> for Multisig<'a, T>
+where
+    T: FromAccountInfo<'a> + ReadableAccount,
+    T::DataType: MultisigData,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [account, signers @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let multisig = Multisig::try_new(T::try_from_info(account)?, signers)?;
+        *accounts = &[];
+
+        Ok(multisig)
+    }
+}
@@ -1,9 +1,16 @@
 use {
     num_traits::{FromPrimitive, ToPrimitive},
     thiserror::Error,
-    typhoon_program::{msg, program_error::ProgramError},
+    typhoon_program::{log_pubkey, msg, program_error::ProgramError, pubkey::Pubkey},
 };
 
+mod macros;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use typhoon_program::{log_pubkey, msg};
+}
+
 /// Maybe rework with thiserror 2.0
 #[derive(Debug, Error)]
 pub enum Error {
@@ -21,6 +28,51 @@ pub enum Error {
 
     #[error("The current owner of this account is not the expected one")]
     AccountOwnedByWrongProgram,
+
+    #[error("The given program id is not the expected one")]
+    InvalidProgramId,
+
+    #[error("The token account mint is not the expected one")]
+    TokenMintMismatch,
+
+    #[error("The token account owner is not the expected one")]
+    TokenOwnerMismatch,
+
+    #[error("The mint authority is not the expected one")]
+    MintAuthorityMismatch,
+
+    #[error("The mint decimals are not the expected ones")]
+    MintDecimalsMismatch,
+
+    #[error("The account is not the associated token account of the authority and the mint")]
+    AssociatedTokenAddressMismatch,
+
+    #[error("The instruction must be invoked by the transaction and not through a CPI")]
+    NotTopLevelInstruction,
+
+    #[error("No precompile instruction verified the expected signature")]
+    SignatureNotVerified,
+
+    #[error("The account discriminator is not the expected one")]
+    AccountDiscriminatorMismatch,
+
+    #[error("The account address is not the one derived from its seeds")]
+    AccountAddressMismatch,
+
+    #[error("The signer is not the upgrade authority of the program")]
+    UpgradeAuthorityMismatch,
+
+    #[error("The signer does not hold the required role")]
+    RoleNotGranted,
+
+    #[error("The program is paused")]
+    ProgramPaused,
+
+    #[error("The signer is not the owner of the role registry")]
+    RegistryOwnerMismatch,
+
+    #[error("Not enough members of the multisig signed the transaction")]
+    MultisigThresholdNotMet,
 }
 
 impl FromPrimitive for Error {
@@ -31,6 +83,21 @@ impl FromPrimitive for Error {
             3002 => Some(Error::AccountNotMutable),
             3003 => Some(Error::AccountNotSigner),
             3004 => Some(Error::AccountOwnedByWrongProgram),
+            3005 => Some(Error::InvalidProgramId),
+            3006 => Some(Error::TokenMintMismatch),
+            3007 => Some(Error::TokenOwnerMismatch),
+            3008 => Some(Error::MintAuthorityMismatch),
+            3009 => Some(Error::MintDecimalsMismatch),
+            3010 => Some(Error::AssociatedTokenAddressMismatch),
+            3011 => Some(Error::NotTopLevelInstruction),
+            3012 => Some(Error::SignatureNotVerified),
+            3013 => Some(Error::AccountDiscriminatorMismatch),
+            3014 => Some(Error::AccountAddressMismatch),
+            3015 => Some(Error::UpgradeAuthorityMismatch),
+            3016 => Some(Error::RoleNotGranted),
+            3017 => Some(Error::ProgramPaused),
+            3018 => Some(Error::RegistryOwnerMismatch),
+            3019 => Some(Error::MultisigThresholdNotMet),
             _ => None,
         }
     }
@@ -48,6 +115,21 @@ impl ToPrimitive for Error {
             Error::AccountNotMutable => Some(3002),
             Error::AccountNotSigner => Some(3003),
             Error::AccountOwnedByWrongProgram => Some(3004),
+            Error::InvalidProgramId => Some(3005),
+            Error::TokenMintMismatch => Some(3006),
+            Error::TokenOwnerMismatch => Some(3007),
+            Error::MintAuthorityMismatch => Some(3008),
+            Error::MintDecimalsMismatch => Some(3009),
+            Error::AssociatedTokenAddressMismatch => Some(3010),
+            Error::NotTopLevelInstruction => Some(3011),
+            Error::SignatureNotVerified => Some(3012),
+            Error::AccountDiscriminatorMismatch => Some(3013),
+            Error::AccountAddressMismatch => Some(3014),
+            Error::UpgradeAuthorityMismatch => Some(3015),
+            Error::RoleNotGranted => Some(3016),
+            Error::ProgramPaused => Some(3017),
+            Error::RegistryOwnerMismatch => Some(3018),
+            Error::MultisigThresholdNotMet => Some(3019),
         }
     }
 
@@ -62,3 +144,9 @@ impl From<Error> for ProgramError {
         ProgramError::Custom(value.to_u32().unwrap())
     }
 }
+
+/// Logs the context, the field and the key of the account which failed its checks.
+pub fn log_account_error(context: &str, field: &str, key: &Pubkey) {
+    msg!("[CONTEXT] {}::{}", context, field);
+    log_pubkey(key);
+}
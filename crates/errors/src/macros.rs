@@ -0,0 +1,111 @@
+/// Ensures that a condition is true, otherwise returns the given error.
+///
+/// ```ignore
+/// require!(ctx.args.amount > 0, Error::InvalidArgument);
+/// ```
+#[macro_export]
+macro_rules! require {
+    ($invariant:expr, $error:expr $(,)?) => {
+        if !($invariant) {
+            return Err($error.into());
+        }
+    };
+}
+
+/// Ensures that two values are equal, logging both of them on failure.
+#[macro_export]
+macro_rules! require_eq {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, ==, $error)
+    };
+}
+
+/// Ensures that two values are not equal, logging both of them on failure.
+#[macro_export]
+macro_rules! require_neq {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, !=, $error)
+    };
+}
+
+/// Ensures that the first value is strictly greater than the second one.
+#[macro_export]
+macro_rules! require_gt {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, >, $error)
+    };
+}
+
+/// Ensures that the first value is greater than or equal to the second one.
+#[macro_export]
+macro_rules! require_gte {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, >=, $error)
+    };
+}
+
+/// Ensures that the first value is strictly lower than the second one.
+#[macro_export]
+macro_rules! require_lt {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, <, $error)
+    };
+}
+
+/// Ensures that the first value is lower than or equal to the second one.
+#[macro_export]
+macro_rules! require_lte {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_cmp!($left, $right, <=, $error)
+    };
+}
+
+/// Ensures that two public keys are equal, logging both of them in base58 on failure.
+#[macro_export]
+macro_rules! require_keys_eq {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_keys_cmp!($left, $right, ==, $error)
+    };
+}
+
+/// Ensures that two public keys are not equal, logging both of them in base58 on failure.
+#[macro_export]
+macro_rules! require_keys_neq {
+    ($left:expr, $right:expr, $error:expr $(,)?) => {
+        $crate::__require_keys_cmp!($left, $right, !=, $error)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_cmp {
+    ($left:expr, $right:expr, $op:tt, $error:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left $op *right) {
+                    $crate::__private::msg!("Left: {}", left);
+                    $crate::__private::msg!("Right: {}", right);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_keys_cmp {
+    ($left:expr, $right:expr, $op:tt, $error:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !(*left $op *right) {
+                    $crate::__private::msg!("Left:");
+                    $crate::__private::log_pubkey(left);
+                    $crate::__private::msg!("Right:");
+                    $crate::__private::log_pubkey(right);
+                    return Err($error.into());
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,34 @@
+use {
+    proc_macro::TokenStream,
+    quote::{quote, ToTokens},
+    sha2::{Digest, Sha256},
+    syn::{parse_macro_input, spanned::Spanned, Error, Item},
+};
+
+#[proc_macro_attribute]
+pub fn event(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as Item);
+    let Item::Struct(ref item_struct) = item else {
+        return Error::new(item.span(), "Invalid event type")
+            .into_compile_error()
+            .into();
+    };
+    let name = &item_struct.ident;
+    let (_, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+
+    // Same derivation as Anchor so existing clients can decode the events
+    let hash = Sha256::digest(format!("event:{name}"));
+    let discriminator = &hash[..8];
+
+    quote! {
+        #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+        #[repr(C)]
+        #item
+
+        impl Event for #name #ty_generics #where_clause {
+            const DISCRIMINATOR: [u8; 8] = [#(#discriminator),*];
+        }
+    }
+    .into_token_stream()
+    .into()
+}
@@ -32,6 +32,16 @@ impl ToTokens for Handlers {
             }
         });
 
+        let event_cpi = if cfg!(feature = "event-cpi") {
+            quote! {
+                if instruction_data.starts_with(&EVENT_IX_TAG_LE) {
+                    return handle_event_cpi(&crate::EVENT_AUTHORITY.0, accounts);
+                }
+            }
+        } else {
+            quote!()
+        };
+
         let expanded = quote! {
             program::program_entrypoint!(process_instruction);
 
@@ -40,6 +50,8 @@ impl ToTokens for Handlers {
                 accounts: &[program::RawAccountInfo],
                 instruction_data: &[u8],
             ) -> program::ProgramResult {
+                #event_cpi
+
                 let (instruction_discriminant, instruction_data_inner) = instruction_data.split_at(1);
                 match instruction_discriminant[0] {
                     #(#instructions)*
@@ -30,4 +30,8 @@ impl Docs {
     pub fn into_vec(self) -> Vec<String> {
         self.0
     }
+
+    pub fn to_vec(&self) -> Vec<String> {
+        self.0.clone()
+    }
 }
@@ -0,0 +1,70 @@
+use {
+    crate::{doc::Docs, ty::FieldType},
+    syn::{Expr, ExprArray, ExprLit, ImplItem, ItemImpl, ItemStruct, Lit},
+};
+
+#[derive(Debug)]
+pub struct EventField {
+    pub name: String,
+    pub docs: Docs,
+    pub ty: FieldType,
+}
+
+/// An event declared with `#[event]`, logged by `emit!` or `emit_cpi!`.
+#[derive(Debug)]
+pub struct Event {
+    pub name: String,
+    pub docs: Docs,
+    pub discriminator: Vec<u8>,
+    pub fields: Vec<EventField>,
+}
+
+impl Event {
+    /// Builds the event from its struct and its expanded `impl Event`.
+    pub fn new(item_struct: &ItemStruct, item_impl: &ItemImpl) -> Self {
+        let fields = item_struct
+            .fields
+            .iter()
+            .map(|field| EventField {
+                // TODO field with no name
+                name: field
+                    .ident
+                    .as_ref()
+                    .map(|i| i.to_string())
+                    .unwrap_or_default(),
+                docs: Docs::from(field.attrs.as_slice()),
+                ty: FieldType::from(&field.ty),
+            })
+            .collect();
+
+        Event {
+            name: item_struct.ident.to_string(),
+            docs: Docs::from(item_struct.attrs.as_slice()),
+            discriminator: extract_discriminator(item_impl).unwrap_or_default(),
+            fields,
+        }
+    }
+}
+
+fn extract_discriminator(item_impl: &ItemImpl) -> Option<Vec<u8>> {
+    let expr = item_impl.items.iter().find_map(|item| match item {
+        ImplItem::Const(item_const) if item_const.ident == "DISCRIMINATOR" => {
+            Some(&item_const.expr)
+        }
+        _ => None,
+    })?;
+
+    let Expr::Array(ExprArray { elems, .. }) = expr else {
+        return None;
+    };
+
+    elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => int.base10_parse().ok(),
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,82 @@
+use {
+    crate::{event::Event, ty::FieldType},
+    anchor_lang_idl_spec::{
+        IdlArrayLen, IdlDefinedFields, IdlEvent, IdlField, IdlRepr, IdlReprModifier,
+        IdlSerialization, IdlType, IdlTypeDef, IdlTypeDefTy,
+    },
+};
+
+impl From<&FieldType> for IdlType {
+    fn from(value: &FieldType) -> Self {
+        match value {
+            FieldType::Bool => IdlType::Bool,
+            FieldType::U8 => IdlType::U8,
+            FieldType::I8 => IdlType::I8,
+            FieldType::U16 => IdlType::U16,
+            FieldType::I16 => IdlType::I16,
+            FieldType::U32 => IdlType::U32,
+            FieldType::I32 => IdlType::I32,
+            FieldType::U64 => IdlType::U64,
+            FieldType::I64 => IdlType::I64,
+            FieldType::U128 => IdlType::U128,
+            FieldType::I128 => IdlType::I128,
+            FieldType::Pubkey => IdlType::Pubkey,
+            FieldType::String { .. } => IdlType::String,
+            FieldType::Option(ty) => IdlType::Option(Box::new(IdlType::from(&**ty))),
+            FieldType::Array(ty, len) => {
+                IdlType::Array(Box::new(IdlType::from(&**ty)), IdlArrayLen::Value(*len))
+            }
+            FieldType::Vec { ty, .. } => IdlType::Vec(Box::new(IdlType::from(&**ty))),
+            FieldType::Defined(name) => IdlType::Defined {
+                name: name.clone(),
+                generics: Vec::new(),
+            },
+        }
+    }
+}
+
+impl From<&Event> for IdlEvent {
+    fn from(value: &Event) -> Self {
+        IdlEvent {
+            name: value.name.clone(),
+            discriminator: value.discriminator.clone(),
+        }
+    }
+}
+
+impl From<&Event> for IdlTypeDef {
+    fn from(value: &Event) -> Self {
+        let fields = value
+            .fields
+            .iter()
+            .map(|field| IdlField {
+                name: field.name.clone(),
+                docs: field.docs.to_vec(),
+                ty: IdlType::from(&field.ty),
+            })
+            .collect();
+
+        // `#[event]` derives `Pod` on a `#[repr(C)]` struct
+        IdlTypeDef {
+            name: value.name.clone(),
+            docs: value.docs.to_vec(),
+            serialization: IdlSerialization::Bytemuck,
+            repr: Some(IdlRepr::C(IdlReprModifier {
+                packed: false,
+                align: None,
+            })),
+            generics: Vec::new(),
+            ty: IdlTypeDefTy::Struct {
+                fields: Some(IdlDefinedFields::Named(fields)),
+            },
+        }
+    }
+}
+
+/// The `events` entries of the IDL, with the `types` entries describing their fields.
+pub fn events_idl(events: &[Event]) -> (Vec<IdlEvent>, Vec<IdlTypeDef>) {
+    events
+        .iter()
+        .map(|event| (IdlEvent::from(event), IdlTypeDef::from(event)))
+        .unzip()
+}
@@ -70,8 +70,18 @@ fn extract_account_flags(ty: &Type, account_flags: &mut AccountFlags) {
                     extract_account_flags(inner_ty, account_flags);
                 }
             }
-            "Signer" => account_flags.is_signer = true,
-            _ => (),
+            "Signer" => {
+                account_flags.is_signer = true;
+                if let Some(inner_ty) = extract_ty_from_arguments(arguments) {
+                    extract_account_flags(inner_ty, account_flags);
+                }
+            }
+            // Wrappers deriving `AccountWrapper` take the flags of the account they wrap.
+            _ => {
+                if let Some(inner_ty) = extract_ty_from_arguments(arguments) {
+                    extract_account_flags(inner_ty, account_flags);
+                }
+            }
         }
     }
 }
@@ -1,8 +1,11 @@
 mod doc;
+mod event;
+mod idl;
 mod instruction;
 pub mod parsing;
+mod ty;
 
-pub use {doc::*, instruction::*};
+pub use {doc::*, event::*, idl::*, instruction::*, ty::*};
 
 // pub struct StateField<'a> {
 //     pub name: &'a Ident,
@@ -3,7 +3,7 @@ use {
         path::Path,
         process::{Command, Stdio},
     },
-    typhoon_metadata_extractor::parsing::ParsingContext,
+    typhoon_metadata_extractor::{events_idl, parsing::ParsingContext},
 };
 
 pub fn main() {
@@ -47,6 +47,10 @@ pub fn main() {
 
         println!("{context:?}");
 
+        let (events, types) = events_idl(&context.extract_events(&file));
+        let idl = serde_json::json!({ "events": events, "types": types });
+        println!("{}", serde_json::to_string_pretty(&idl).unwrap());
+
         // let context_idents: Vec<&Ident> = file
         //     .items
         //     .iter()
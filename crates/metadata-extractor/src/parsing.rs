@@ -1,10 +1,14 @@
-use syn::{Expr, File, Ident, Item, ItemFn, Stmt, Type};
+use {
+    crate::event::Event,
+    syn::{Expr, File, Ident, Item, ItemFn, Stmt, Type},
+};
 
 #[derive(Default, Debug)]
 pub struct ParsingContext<'a> {
     pub contexts: Vec<&'a Ident>,
     pub instructions: Vec<&'a Ident>,
     pub accounts: Vec<&'a Ident>,
+    pub events: Vec<&'a Ident>,
 }
 
 impl<'a> From<&'a File> for ParsingContext<'a> {
@@ -19,6 +23,10 @@ impl<'a> From<&'a File> for ParsingContext<'a> {
                 if let Some(ident) = extract_ident(item_impl, "Owner") {
                     context.accounts.push(ident);
                 }
+
+                if let Some(ident) = extract_ident(item_impl, "Event") {
+                    context.events.push(ident);
+                }
             }
             Item::Fn(item_fn) => {
                 if let Some(instructions) = extract_instruction_idents(item_fn) {
@@ -31,6 +39,29 @@ impl<'a> From<&'a File> for ParsingContext<'a> {
     }
 }
 
+impl ParsingContext<'_> {
+    /// Collects the events found while parsing `file` with their fields and discriminator.
+    pub fn extract_events(&self, file: &File) -> Vec<Event> {
+        self.events
+            .iter()
+            .filter_map(|name| {
+                let item_struct = file.items.iter().find_map(|item| match item {
+                    Item::Struct(item_struct) if &&item_struct.ident == name => Some(item_struct),
+                    _ => None,
+                })?;
+                let item_impl = file.items.iter().find_map(|item| match item {
+                    Item::Impl(item_impl) if extract_ident(item_impl, "Event") == Some(name) => {
+                        Some(item_impl)
+                    }
+                    _ => None,
+                })?;
+
+                Some(Event::new(item_struct, item_impl))
+            })
+            .collect()
+    }
+}
+
 fn extract_ident<'a>(item_impl: &'a syn::ItemImpl, trait_name: &str) -> Option<&'a Ident> {
     let trait_ = item_impl.trait_.as_ref()?;
     let segment = trait_.1.segments.last()?;
@@ -0,0 +1,97 @@
+use syn::{Expr, ExprLit, GenericArgument, Lit, PathArguments, Type};
+
+/// Type of a field of an account, of the arguments or of an event, as exposed to the clients.
+///
+/// The `Pod*` types of `typhoon-pod` are described by the value they hold.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+    Pubkey,
+    String { max_len: usize },
+    Option(Box<FieldType>),
+    Array(Box<FieldType>, usize),
+    Vec { ty: Box<FieldType>, max_len: usize },
+    Defined(String),
+}
+
+impl From<&Type> for FieldType {
+    fn from(value: &Type) -> Self {
+        match value {
+            Type::Array(array) => match extract_len(&array.len) {
+                Some(len) => FieldType::Array(Box::new(FieldType::from(&*array.elem)), len),
+                None => FieldType::Defined(String::from("array")),
+            },
+            Type::Path(type_path) => {
+                let Some(segment) = type_path.path.segments.last() else {
+                    return FieldType::Defined(String::new());
+                };
+                let name = segment.ident.to_string();
+                let args = extract_generic_args(&segment.arguments);
+
+                match (name.as_str(), args.as_slice()) {
+                    ("bool" | "PodBool", []) => FieldType::Bool,
+                    ("u8", []) => FieldType::U8,
+                    ("i8", []) => FieldType::I8,
+                    ("u16" | "PodU16", []) => FieldType::U16,
+                    ("i16", []) => FieldType::I16,
+                    ("u32" | "PodU32", []) => FieldType::U32,
+                    ("i32", []) => FieldType::I32,
+                    ("u64" | "PodU64", []) => FieldType::U64,
+                    ("i64" | "PodI64", []) => FieldType::I64,
+                    ("u128" | "PodU128", []) => FieldType::U128,
+                    ("i128", []) => FieldType::I128,
+                    ("Pubkey", []) => FieldType::Pubkey,
+                    ("PodPubkeyOption", []) => FieldType::Option(Box::new(FieldType::Pubkey)),
+                    ("PodOption", [GenericArgument::Type(ty)]) => {
+                        FieldType::Option(Box::new(FieldType::from(ty)))
+                    }
+                    ("FixedString", [GenericArgument::Const(len)]) => match extract_len(len) {
+                        Some(max_len) => FieldType::String { max_len },
+                        None => FieldType::Defined(name),
+                    },
+                    ("FixedVec", [GenericArgument::Type(ty), GenericArgument::Const(len)]) => {
+                        match extract_len(len) {
+                            Some(max_len) => FieldType::Vec {
+                                ty: Box::new(FieldType::from(ty)),
+                                max_len,
+                            },
+                            None => FieldType::Defined(name),
+                        }
+                    }
+                    _ => FieldType::Defined(name),
+                }
+            }
+            _ => FieldType::Defined(String::new()),
+        }
+    }
+}
+
+fn extract_generic_args(args: &PathArguments) -> Vec<&GenericArgument> {
+    match args {
+        PathArguments::AngleBracketed(generic_args) => generic_args.args.iter().collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn extract_len(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(expr, None)] => extract_len(expr),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,87 @@
+use {
+    anchor_lang_idl_spec::{IdlDefinedFields, IdlField, IdlSerialization, IdlType, IdlTypeDefTy},
+    typhoon_metadata_extractor::{events_idl, parsing::ParsingContext, FieldType},
+};
+
+fn events_file() -> syn::File {
+    syn::parse_file(
+        r#"
+        /// Emitted on each deposit.
+        #[derive(bytemuck::Pod, bytemuck::Zeroable, Clone, Copy)]
+        #[repr(C)]
+        pub struct Deposited {
+            /// The deposited lamports.
+            pub amount: u64,
+            pub owner: Pubkey,
+        }
+
+        impl Event for Deposited {
+            const DISCRIMINATOR: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        }
+
+        pub struct NotAnEvent {
+            pub amount: u64,
+        }
+        "#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn events() {
+    let file = events_file();
+    let context = ParsingContext::from(&file);
+    let events = context.extract_events(&file);
+
+    let [event] = events.as_slice() else {
+        panic!("expected one event, got {events:?}");
+    };
+    assert_eq!(event.name, "Deposited");
+    assert_eq!(event.discriminator, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    let [amount, owner] = event.fields.as_slice() else {
+        panic!("expected two fields, got {:?}", event.fields);
+    };
+    assert_eq!(amount.name, "amount");
+    assert_eq!(amount.ty, FieldType::U64);
+    assert_eq!(owner.name, "owner");
+    assert_eq!(owner.ty, FieldType::Pubkey);
+}
+
+#[test]
+fn events_idl_entries() {
+    let file = events_file();
+    let context = ParsingContext::from(&file);
+    let (events, types) = events_idl(&context.extract_events(&file));
+
+    let [event] = events.as_slice() else {
+        panic!("expected one event, got {events:?}");
+    };
+    assert_eq!(event.name, "Deposited");
+    assert_eq!(event.discriminator, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    // The event is described in the types with the same name
+    let [ty] = types.as_slice() else {
+        panic!("expected one type, got {types:?}");
+    };
+    assert_eq!(ty.name, "Deposited");
+    assert_eq!(ty.docs, ["Emitted on each deposit."]);
+    assert_eq!(ty.serialization, IdlSerialization::Bytemuck);
+    assert_eq!(
+        ty.ty,
+        IdlTypeDefTy::Struct {
+            fields: Some(IdlDefinedFields::Named(vec![
+                IdlField {
+                    name: String::from("amount"),
+                    docs: vec![String::from("The deposited lamports.")],
+                    ty: IdlType::U64,
+                },
+                IdlField {
+                    name: String::from("owner"),
+                    docs: Vec::new(),
+                    ty: IdlType::Pubkey,
+                },
+            ])),
+        }
+    );
+}
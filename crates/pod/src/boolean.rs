@@ -0,0 +1,50 @@
+use {
+    crate::Unaligned,
+    bytemuck::{Pod, Zeroable},
+    std::fmt,
+};
+
+/// A `bool` stored as a byte, any value other than 0 being `true`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct PodBool(u8);
+
+unsafe impl Zeroable for PodBool {}
+unsafe impl Pod for PodBool {}
+unsafe impl Unaligned for PodBool {}
+
+impl PodBool {
+    pub const fn new(value: bool) -> Self {
+        PodBool(value as u8)
+    }
+
+    pub const fn get(self) -> bool {
+        self.0 != 0
+    }
+}
+
+impl From<bool> for PodBool {
+    fn from(value: bool) -> Self {
+        PodBool::new(value)
+    }
+}
+
+impl From<PodBool> for bool {
+    fn from(value: PodBool) -> Self {
+        value.get()
+    }
+}
+
+impl PartialEq for PodBool {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl Eq for PodBool {}
+
+impl fmt::Debug for PodBool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
@@ -0,0 +1,82 @@
+use typhoon_program::program_error::ProgramError;
+
+/// A set of bits, like the used slots of a registry.
+pub struct Bitmap<'a> {
+    bits: &'a mut [u8],
+}
+
+impl<'a> Bitmap<'a> {
+    pub fn load(data: &'a mut [u8]) -> Self {
+        Bitmap { bits: data }
+    }
+
+    /// Length of the data holding `len` bits.
+    pub const fn space(len: usize) -> usize {
+        len.div_ceil(8)
+    }
+
+    /// Number of bits.
+    pub fn len(&self) -> usize {
+        self.bits.len() * 8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<bool> {
+        let byte = self.bits.get(index / 8)?;
+
+        Some(byte & (1 << (index % 8)) != 0)
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), ProgramError> {
+        let byte = self
+            .bits
+            .get_mut(index / 8)
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        if value {
+            *byte |= 1 << (index % 8);
+        } else {
+            *byte &= !(1 << (index % 8));
+        }
+
+        Ok(())
+    }
+
+    /// Flips the bit, returning its new value.
+    pub fn toggle(&mut self, index: usize) -> Result<bool, ProgramError> {
+        let value = !self.get(index).ok_or(ProgramError::InvalidArgument)?;
+        self.set(index, value)?;
+
+        Ok(value)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Index of the first unset bit, usually a free slot.
+    pub fn first_zero(&self) -> Option<usize> {
+        self.bits
+            .iter()
+            .position(|byte| *byte != u8::MAX)
+            .map(|i| i * 8 + self.bits[i].trailing_ones() as usize)
+    }
+
+    /// Index of the first set bit.
+    pub fn first_one(&self) -> Option<usize> {
+        self.bits
+            .iter()
+            .position(|byte| *byte != 0)
+            .map(|i| i * 8 + self.bits[i].trailing_zeros() as usize)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+}
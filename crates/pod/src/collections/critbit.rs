@@ -0,0 +1,401 @@
+use {
+    super::{split_header, Slab},
+    bytemuck::{Pod, Zeroable},
+    typhoon_program::program_error::ProgramError,
+};
+
+const INNER: u32 = 1;
+const LEAF: u32 = 2;
+
+/// Deepest path of a tree: one inner node per bit of the key and the leaf.
+const MAX_DEPTH: usize = 129;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct TreeHeader {
+    /// Index + 1 of the root node, 0 when the tree is empty.
+    root: u32,
+    len: u32,
+}
+
+unsafe impl Zeroable for TreeHeader {}
+unsafe impl Pod for TreeHeader {}
+
+/// An inner node splitting its leaves on the bit `shift`, or a leaf pointing to its value with
+/// `children[0]`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct Node {
+    kind: u32,
+    shift: u32,
+    children: [u32; 2],
+    key: [u64; 2],
+}
+
+unsafe impl Zeroable for Node {}
+unsafe impl Pod for Node {}
+
+impl Node {
+    fn key(&self) -> u128 {
+        (self.key[1] as u128) << 64 | self.key[0] as u128
+    }
+
+    fn leaf(key: u128, value: u32) -> Self {
+        Node {
+            kind: LEAF,
+            shift: 0,
+            children: [value, 0],
+            key: [key as u64, (key >> 64) as u64],
+        }
+    }
+}
+
+/// Side of the inner node splitting on `shift` holding `key`.
+fn direction(key: u128, shift: u32) -> usize {
+    (key >> shift) as usize & 1
+}
+
+/// Where the index of a node is stored.
+#[derive(Clone, Copy)]
+enum Link {
+    Root,
+    Child(u32, usize),
+}
+
+/// A crit-bit tree ordered by `u128` keys, like the price and sequence number of the orders of
+/// a book side.
+///
+/// The nodes and the values are stored in two slabs sharing the data after the header.
+pub struct CritbitTree<'a, T>
+where
+    T: Pod,
+{
+    header: &'a mut TreeHeader,
+    nodes: Slab<'a, Node>,
+    values: Slab<'a, T>,
+}
+
+impl<'a, T> CritbitTree<'a, T>
+where
+    T: Pod,
+{
+    pub fn load(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        let capacity = Self::capacity_of(data.len());
+        let (header, rest) = split_header::<TreeHeader>(data)?;
+        let nodes_len = Slab::<Node>::space(2 * capacity).min(rest.len());
+        let (nodes, values) = rest.split_at_mut(nodes_len);
+
+        Ok(CritbitTree {
+            header,
+            nodes: Slab::load(nodes)?,
+            values: Slab::load(values)?,
+        })
+    }
+
+    /// Length of the data holding `capacity` values.
+    pub const fn space(capacity: usize) -> usize {
+        core::mem::size_of::<TreeHeader>()
+            + Slab::<Node>::space(2 * capacity)
+            + Slab::<T>::space(capacity)
+    }
+
+    const fn capacity_of(len: usize) -> usize {
+        let entry = 2 * (core::mem::size_of::<u32>() + core::mem::size_of::<Node>())
+            + core::mem::size_of::<u32>()
+            + core::mem::size_of::<T>();
+        let mut capacity = len / entry;
+        while capacity > 0 && Self::space(capacity) > len {
+            capacity -= 1;
+        }
+
+        capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.values.capacity().min(self.nodes.capacity() / 2)
+    }
+
+    pub fn len(&self) -> usize {
+        self.header.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.header.root == 0
+    }
+
+    fn node(&self, index: u32) -> Result<&Node, ProgramError> {
+        self.nodes
+            .get(index)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    fn set_link(&mut self, link: Link, index: u32) -> Result<(), ProgramError> {
+        match link {
+            Link::Root => self.header.root = index + 1,
+            Link::Child(parent, side) => {
+                self.nodes
+                    .get_mut(parent)
+                    .ok_or(ProgramError::InvalidAccountData)?
+                    .children[side] = index;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Bit where `key` leaves the tree holding `closest_key`, with the node to split there and
+    /// where its index is stored.
+    fn split(&self, key: u128, closest_key: u128) -> Result<(u32, Link, u32), ProgramError> {
+        // Highest bit where the key differs from the tree
+        let shift = 127 - (key ^ closest_key).leading_zeros();
+
+        let mut link = Link::Root;
+        let mut index = self.header.root - 1;
+        for _ in 0..MAX_DEPTH {
+            let node = self.node(index)?;
+            if node.kind == LEAF || node.shift < shift {
+                return Ok((shift, link, index));
+            }
+
+            let side = direction(key, node.shift);
+            link = Link::Child(index, side);
+            index = node.children[side];
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// The leaf closest to `key`, sharing the bits of the inner nodes on its path.
+    fn closest_leaf(&self, key: u128) -> Result<Option<&Node>, ProgramError> {
+        let Some(mut index) = self.header.root.checked_sub(1) else {
+            return Ok(None);
+        };
+
+        for _ in 0..MAX_DEPTH {
+            let node = self.node(index)?;
+            if node.kind == LEAF {
+                return Ok(Some(node));
+            }
+
+            index = node.children[direction(key, node.shift)];
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    pub fn get(&self, key: u128) -> Result<Option<&T>, ProgramError> {
+        match self.closest_leaf(key)? {
+            Some(leaf) if leaf.key() == key => self
+                .values
+                .get(leaf.children[0])
+                .map(Some)
+                .ok_or(ProgramError::InvalidAccountData),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn get_mut(&mut self, key: u128) -> Result<Option<&mut T>, ProgramError> {
+        let value = match self.closest_leaf(key)? {
+            Some(leaf) if leaf.key() == key => leaf.children[0],
+            _ => return Ok(None),
+        };
+
+        self.values
+            .get_mut(value)
+            .map(Some)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    pub fn contains_key(&self, key: u128) -> Result<bool, ProgramError> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Inserts `value` at `key`, returning the value it replaces.
+    pub fn insert(&mut self, key: u128, value: T) -> Result<Option<T>, ProgramError> {
+        let closest_key = match self.closest_leaf(key)? {
+            Some(leaf) if leaf.key() == key => {
+                let slot = leaf.children[0];
+                let previous = self
+                    .values
+                    .get_mut(slot)
+                    .ok_or(ProgramError::InvalidAccountData)?;
+
+                return Ok(Some(core::mem::replace(previous, value)));
+            }
+            Some(leaf) => Some(leaf.key()),
+            None => None,
+        };
+
+        if self.values.remaining() < 1 || self.nodes.remaining() < 2 {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        // The new inner node goes above the first node splitting on a lower bit, found before
+        // inserting anything so a corrupted tree is left as is.
+        let split = match closest_key {
+            Some(closest_key) => Some(self.split(key, closest_key)?),
+            None => None,
+        };
+
+        let value = self.values.insert(value)?;
+        let leaf = self.nodes.insert(Node::leaf(key, value))?;
+
+        let Some((shift, link, index)) = split else {
+            self.header.root = leaf + 1;
+            self.header.len = 1;
+            return Ok(None);
+        };
+
+        let mut children = [index, index];
+        children[direction(key, shift)] = leaf;
+        let inner = self.nodes.insert(Node {
+            kind: INNER,
+            shift,
+            children,
+            key: [0; 2],
+        })?;
+        self.set_link(link, inner)?;
+        self.header.len += 1;
+
+        Ok(None)
+    }
+
+    /// Removes the value at `key`.
+    pub fn remove(&mut self, key: u128) -> Result<Option<T>, ProgramError> {
+        let Some(mut index) = self.header.root.checked_sub(1) else {
+            return Ok(None);
+        };
+
+        // Link to the parent of the current node, and the parent with the side of the node
+        let mut grandparent_link = Link::Root;
+        let mut parent: Option<(u32, usize)> = None;
+        let mut depth = 0;
+        loop {
+            let node = self.node(index)?;
+            if node.kind == LEAF {
+                break;
+            }
+
+            depth += 1;
+            if depth > MAX_DEPTH {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            if let Some((parent_index, side)) = parent {
+                grandparent_link = Link::Child(parent_index, side);
+            }
+
+            let side = direction(key, node.shift);
+            parent = Some((index, side));
+            index = node.children[side];
+        }
+
+        let leaf = *self.node(index)?;
+        if leaf.key() != key {
+            return Ok(None);
+        }
+
+        match parent {
+            None => self.header.root = 0,
+            Some((parent_index, side)) => {
+                let sibling = self.node(parent_index)?.children[1 - side];
+                self.set_link(grandparent_link, sibling)?;
+                self.nodes.remove(parent_index);
+            }
+        }
+
+        self.nodes.remove(index);
+        self.header.len -= 1;
+
+        self.values
+            .remove(leaf.children[0])
+            .map(Some)
+            .ok_or(ProgramError::InvalidAccountData)
+    }
+
+    /// The leaf at the end of the `side` children.
+    fn edge(&self, side: usize) -> Result<Option<(u128, &T)>, ProgramError> {
+        let Some(mut index) = self.header.root.checked_sub(1) else {
+            return Ok(None);
+        };
+
+        for _ in 0..MAX_DEPTH {
+            let node = self.node(index)?;
+            if node.kind == LEAF {
+                let value = self
+                    .values
+                    .get(node.children[0])
+                    .ok_or(ProgramError::InvalidAccountData)?;
+
+                return Ok(Some((node.key(), value)));
+            }
+
+            index = node.children[side];
+        }
+
+        Err(ProgramError::InvalidAccountData)
+    }
+
+    /// The entry with the lowest key.
+    pub fn first(&self) -> Result<Option<(u128, &T)>, ProgramError> {
+        self.edge(0)
+    }
+
+    /// The entry with the highest key.
+    pub fn last(&self) -> Result<Option<(u128, &T)>, ProgramError> {
+        self.edge(1)
+    }
+
+    /// Iterates over the entries in the order of the keys.
+    pub fn iter(&self) -> Iter<'_, 'a, T> {
+        let mut iter = Iter {
+            tree: self,
+            stack: [0; MAX_DEPTH + 1],
+            depth: 0,
+        };
+        if let Some(root) = self.header.root.checked_sub(1) {
+            iter.stack[0] = root;
+            iter.depth = 1;
+        }
+
+        iter
+    }
+}
+
+/// Iterator over the entries of a [`CritbitTree`], stopping on a corrupted node.
+pub struct Iter<'t, 'a, T>
+where
+    T: Pod,
+{
+    tree: &'t CritbitTree<'a, T>,
+    stack: [u32; MAX_DEPTH + 1],
+    depth: usize,
+}
+
+impl<'t, T> Iterator for Iter<'t, '_, T>
+where
+    T: Pod,
+{
+    type Item = (u128, &'t T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.depth > 0 {
+            self.depth -= 1;
+            let node = self.tree.node(self.stack[self.depth]).ok()?;
+
+            if node.kind == LEAF {
+                return Some((node.key(), self.tree.values.get(node.children[0])?));
+            }
+
+            if self.depth + 2 > self.stack.len() {
+                return None;
+            }
+
+            self.stack[self.depth] = node.children[1];
+            self.stack[self.depth + 1] = node.children[0];
+            self.depth += 2;
+        }
+
+        None
+    }
+}
@@ -0,0 +1,37 @@
+//! Data structures stored in the data of an account, usually borrowed with
+//! `WritableAccount::mut_data`. A zeroed data is a valid empty collection and the capacity is
+//! deduced from the length of the data, which must be 8 bytes aligned.
+
+mod bitmap;
+mod critbit;
+mod ring_buffer;
+mod slab;
+
+pub use {bitmap::*, critbit::*, ring_buffer::*, slab::*};
+use {bytemuck::Pod, typhoon_program::program_error::ProgramError};
+
+/// Splits the header of a collection from its content.
+fn split_header<H: Pod>(data: &mut [u8]) -> Result<(&mut H, &mut [u8]), ProgramError> {
+    if data.len() < core::mem::size_of::<H>() {
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    let (header, rest) = data.split_at_mut(core::mem::size_of::<H>());
+    let header =
+        bytemuck::try_from_bytes_mut(header).map_err(|_| ProgramError::InvalidAccountData)?;
+
+    Ok((header, rest))
+}
+
+/// Casts the first `len` items of `data`.
+fn cast_items<T: Pod>(data: &mut [u8], len: usize) -> Result<&mut [T], ProgramError> {
+    let bytes = data
+        .get_mut(..len * core::mem::size_of::<T>())
+        .ok_or(ProgramError::AccountDataTooSmall)?;
+
+    bytemuck::try_cast_slice_mut(bytes).map_err(|_| ProgramError::InvalidAccountData)
+}
+
+const fn round_up(value: usize, align: usize) -> usize {
+    value.div_ceil(align) * align
+}
@@ -0,0 +1,141 @@
+use {
+    super::{cast_items, split_header},
+    bytemuck::{Pod, Zeroable},
+    typhoon_program::program_error::ProgramError,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct RingHeader {
+    head: u64,
+    len: u64,
+}
+
+unsafe impl Zeroable for RingHeader {}
+unsafe impl Pod for RingHeader {}
+
+/// A FIFO queue of fixed capacity, like an event queue.
+pub struct RingBuffer<'a, T>
+where
+    T: Pod,
+{
+    header: &'a mut RingHeader,
+    items: &'a mut [T],
+}
+
+impl<'a, T> RingBuffer<'a, T>
+where
+    T: Pod,
+{
+    pub fn load(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        if core::mem::size_of::<T>() == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (header, rest) = split_header::<RingHeader>(data)?;
+        let capacity = rest.len() / core::mem::size_of::<T>();
+        let items = cast_items(rest, capacity)?;
+
+        if header.len > capacity as u64 || (capacity > 0 && header.head >= capacity as u64) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(RingBuffer { header, items })
+    }
+
+    /// Length of the data holding `capacity` items.
+    pub const fn space(capacity: usize) -> usize {
+        core::mem::size_of::<RingHeader>() + capacity * core::mem::size_of::<T>()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.header.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    fn slot(&self, index: usize) -> usize {
+        (self.header.head as usize + index) % self.capacity()
+    }
+
+    /// Appends `item`, failing if the buffer is full.
+    pub fn push_back(&mut self, item: T) -> Result<(), ProgramError> {
+        if self.is_full() {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        let slot = self.slot(self.len());
+        self.items[slot] = item;
+        self.header.len += 1;
+
+        Ok(())
+    }
+
+    /// Appends `item`, dropping the oldest one if the buffer is full.
+    pub fn push_back_overwrite(&mut self, item: T) -> Result<Option<T>, ProgramError> {
+        let dropped = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        self.push_back(item)?;
+
+        Ok(dropped)
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let head = self.header.head as usize;
+        let item = core::mem::replace(&mut self.items[head], T::zeroed());
+        self.header.head = ((head + 1) % self.capacity()) as u64;
+        self.header.len -= 1;
+
+        Some(item)
+    }
+
+    /// The item at `index` from the oldest one.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len()).then(|| &self.items[self.slot(index)])
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let slot = self.slot(index);
+        Some(&mut self.items[slot])
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Iterates from the oldest item to the newest one.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        (0..self.len()).map(|index| &self.items[self.slot(index)])
+    }
+
+    pub fn clear(&mut self) {
+        self.items.fill(T::zeroed());
+        self.header.head = 0;
+        self.header.len = 0;
+    }
+}
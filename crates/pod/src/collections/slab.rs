@@ -0,0 +1,172 @@
+use {
+    super::{cast_items, round_up, split_header},
+    bytemuck::{Pod, Zeroable},
+    typhoon_program::program_error::ProgramError,
+};
+
+/// Link of an allocated slot, the free slots linking to the next free one.
+const OCCUPIED: u32 = u32::MAX;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct SlabHeader {
+    /// Index + 1 of the first free slot, 0 when the free list is empty.
+    free_head: u32,
+    /// Number of slots used at least once.
+    bump: u32,
+    len: u32,
+    _padding: u32,
+}
+
+unsafe impl Zeroable for SlabHeader {}
+unsafe impl Pod for SlabHeader {}
+
+/// Slots of a fixed capacity reused through a free list, the index of an item staying the same
+/// until it is removed.
+pub struct Slab<'a, T>
+where
+    T: Pod,
+{
+    header: &'a mut SlabHeader,
+    links: &'a mut [u32],
+    items: &'a mut [T],
+}
+
+impl<'a, T> Slab<'a, T>
+where
+    T: Pod,
+{
+    pub fn load(data: &'a mut [u8]) -> Result<Self, ProgramError> {
+        if core::mem::size_of::<T>() == 0 {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let capacity = Self::capacity_of(data.len());
+        let items_offset = Self::items_offset(capacity);
+        let (header, rest) = split_header::<SlabHeader>(data)?;
+        let links_len = items_offset - core::mem::size_of::<SlabHeader>();
+        let (links, items) = rest.split_at_mut(links_len.min(rest.len()));
+        let links = cast_items(links, capacity)?;
+        let items = cast_items(items, capacity)?;
+
+        if header.bump as usize > capacity
+            || header.len > header.bump
+            || header.free_head > header.bump
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Slab {
+            header,
+            links,
+            items,
+        })
+    }
+
+    /// Length of the data holding `capacity` items.
+    pub const fn space(capacity: usize) -> usize {
+        Self::items_offset(capacity) + capacity * core::mem::size_of::<T>()
+    }
+
+    const fn items_offset(capacity: usize) -> usize {
+        round_up(
+            core::mem::size_of::<SlabHeader>() + capacity * core::mem::size_of::<u32>(),
+            core::mem::align_of::<T>(),
+        )
+    }
+
+    /// Capacity of a slab stored in `len` bytes.
+    pub(crate) const fn capacity_of(len: usize) -> usize {
+        let header = core::mem::size_of::<SlabHeader>();
+        if len < header {
+            return 0;
+        }
+
+        let mut capacity =
+            (len - header) / (core::mem::size_of::<u32>() + core::mem::size_of::<T>());
+        while capacity > 0 && Self::space(capacity) > len {
+            capacity -= 1;
+        }
+
+        capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.header.len as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of items which can still be inserted.
+    pub fn remaining(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    pub fn contains(&self, index: u32) -> bool {
+        index < self.header.bump && self.links[index as usize] == OCCUPIED
+    }
+
+    /// Stores `item` in a free slot, returning its index.
+    pub fn insert(&mut self, item: T) -> Result<u32, ProgramError> {
+        let index = if self.header.free_head != 0 {
+            let index = self.header.free_head - 1;
+            let next = self.links[index as usize];
+            if next == OCCUPIED || next > self.header.bump {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            self.header.free_head = next;
+            index
+        } else if (self.header.bump as usize) < self.capacity() {
+            self.header.bump += 1;
+            self.header.bump - 1
+        } else {
+            return Err(ProgramError::AccountDataTooSmall);
+        };
+
+        self.links[index as usize] = OCCUPIED;
+        self.items[index as usize] = item;
+        self.header.len += 1;
+
+        Ok(index)
+    }
+
+    /// Frees the slot at `index`, returning its item.
+    pub fn remove(&mut self, index: u32) -> Option<T> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        let item = core::mem::replace(&mut self.items[index as usize], T::zeroed());
+        self.links[index as usize] = self.header.free_head;
+        self.header.free_head = index + 1;
+        self.header.len -= 1;
+
+        Some(item)
+    }
+
+    pub fn get(&self, index: u32) -> Option<&T> {
+        self.contains(index).then(|| &self.items[index as usize])
+    }
+
+    pub fn get_mut(&mut self, index: u32) -> Option<&mut T> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        Some(&mut self.items[index as usize])
+    }
+
+    /// Iterates over the allocated slots and their index.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &T)> + '_ {
+        (0..self.header.bump)
+            .filter(|index| self.contains(*index))
+            .map(|index| (index, &self.items[index as usize]))
+    }
+}
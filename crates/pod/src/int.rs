@@ -0,0 +1,64 @@
+use {
+    crate::Unaligned,
+    bytemuck::{Pod, Zeroable},
+    std::{cmp::Ordering, fmt},
+};
+
+macro_rules! pod_int {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("A `", stringify!($ty), "` stored in little endian, aligned on 1 byte.")]
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name([u8; core::mem::size_of::<$ty>()]);
+
+        unsafe impl Zeroable for $name {}
+        unsafe impl Pod for $name {}
+        unsafe impl Unaligned for $name {}
+
+        impl $name {
+            pub const fn new(value: $ty) -> Self {
+                $name(value.to_le_bytes())
+            }
+
+            pub const fn get(self) -> $ty {
+                <$ty>::from_le_bytes(self.0)
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(value: $ty) -> Self {
+                $name::new(value)
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+    };
+}
+
+pod_int!(PodU16, u16);
+pod_int!(PodU32, u32);
+pod_int!(PodU64, u64);
+pod_int!(PodU128, u128);
+pod_int!(PodI64, i64);
@@ -0,0 +1,12 @@
+//! `Pod` replacements for the types which can't be read without copy, like `bool`, `Option` or
+//! strings, to use in `#[account]` structs, `#[args]` and events.
+
+pub mod collections;
+
+mod boolean;
+mod int;
+mod option;
+mod string;
+mod vec;
+
+pub use {boolean::*, int::*, option::*, string::*, typhoon_program::bytes::Unaligned, vec::*};
@@ -0,0 +1,158 @@
+use {
+    crate::{PodBool, Unaligned},
+    bytemuck::{Pod, Zeroable},
+    std::fmt,
+    typhoon_program::{program_error::ProgramError, pubkey::Pubkey, pubkey_from_array},
+};
+
+/// An `Option` stored as a flag followed by the value, zeroed when `None`.
+///
+/// The layout is packed so the value is copied out with `get` instead of borrowed.
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct PodOption<T>
+where
+    T: Pod,
+{
+    is_some: PodBool,
+    value: T,
+}
+
+unsafe impl<T> Zeroable for PodOption<T> where T: Pod {}
+unsafe impl<T> Pod for PodOption<T> where T: Pod {}
+unsafe impl<T> Unaligned for PodOption<T> where T: Pod {}
+
+impl<T> PodOption<T>
+where
+    T: Pod,
+{
+    pub fn none() -> Self {
+        Zeroable::zeroed()
+    }
+
+    pub fn some(value: T) -> Self {
+        PodOption {
+            is_some: PodBool::new(true),
+            value,
+        }
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.is_some.get()
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    pub fn get(&self) -> Option<T> {
+        self.is_some().then_some(self.value)
+    }
+
+    pub fn set(&mut self, value: Option<T>) {
+        *self = value.into();
+    }
+}
+
+impl<T> Default for PodOption<T>
+where
+    T: Pod,
+{
+    fn default() -> Self {
+        PodOption::none()
+    }
+}
+
+impl<T> From<Option<T>> for PodOption<T>
+where
+    T: Pod,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or_else(PodOption::none, PodOption::some)
+    }
+}
+
+impl<T> From<PodOption<T>> for Option<T>
+where
+    T: Pod,
+{
+    fn from(value: PodOption<T>) -> Self {
+        value.get()
+    }
+}
+
+impl<T> PartialEq for PodOption<T>
+where
+    T: Pod + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T> fmt::Debug for PodOption<T>
+where
+    T: Pod + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+const NONE_PUBKEY: Pubkey = pubkey_from_array([0; 32]);
+
+/// An optional public key without flag, the zeroed key standing for `None`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct PodPubkeyOption(Pubkey);
+
+unsafe impl Zeroable for PodPubkeyOption {}
+unsafe impl Pod for PodPubkeyOption {}
+unsafe impl Unaligned for PodPubkeyOption {}
+
+impl PodPubkeyOption {
+    pub const fn none() -> Self {
+        PodPubkeyOption(NONE_PUBKEY)
+    }
+
+    /// Fails with the zeroed key which can't be distinguished from `None`.
+    pub fn some(key: Pubkey) -> Result<Self, ProgramError> {
+        if key == NONE_PUBKEY {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(PodPubkeyOption(key))
+    }
+
+    pub fn is_some(&self) -> bool {
+        self.0 != NONE_PUBKEY
+    }
+
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    pub fn get(&self) -> Option<&Pubkey> {
+        self.is_some().then_some(&self.0)
+    }
+}
+
+impl TryFrom<Option<Pubkey>> for PodPubkeyOption {
+    type Error = ProgramError;
+
+    fn try_from(value: Option<Pubkey>) -> Result<Self, Self::Error> {
+        value.map_or(Ok(PodPubkeyOption::none()), PodPubkeyOption::some)
+    }
+}
+
+impl From<PodPubkeyOption> for Option<Pubkey> {
+    fn from(value: PodPubkeyOption) -> Self {
+        value.get().copied()
+    }
+}
+
+impl fmt::Debug for PodPubkeyOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(f)
+    }
+}
@@ -0,0 +1,94 @@
+use {
+    crate::{PodU16, Unaligned},
+    bytemuck::{Pod, Zeroable},
+    std::fmt,
+    typhoon_program::program_error::ProgramError,
+};
+
+/// A UTF-8 string of at most `N` bytes, stored with its length.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FixedString<const N: usize> {
+    len: PodU16,
+    bytes: [u8; N],
+}
+
+unsafe impl<const N: usize> Zeroable for FixedString<N> {}
+unsafe impl<const N: usize> Pod for FixedString<N> {}
+unsafe impl<const N: usize> Unaligned for FixedString<N> {}
+
+impl<const N: usize> FixedString<N> {
+    /// Fails if `value` is longer than `N` bytes.
+    pub fn new(value: &str) -> Result<Self, ProgramError> {
+        let mut string = Self::zeroed();
+        string.set(value)?;
+
+        Ok(string)
+    }
+
+    /// Replaces the content, failing if `value` is longer than `N` bytes.
+    pub fn set(&mut self, value: &str) -> Result<(), ProgramError> {
+        let len = value.len();
+        if len > N || len > u16::MAX as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.bytes[..len].copy_from_slice(value.as_bytes());
+        self.bytes[len..].fill(0);
+        self.len = PodU16::new(len as u16);
+
+        Ok(())
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        (self.len.get() as usize).min(N)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len()]
+    }
+
+    /// Fails if the stored bytes aren't valid UTF-8.
+    pub fn as_str(&self) -> Result<&str, ProgramError> {
+        core::str::from_utf8(self.as_bytes()).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl<const N: usize> PartialEq for FixedString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for FixedString<N> {}
+
+impl<const N: usize> TryFrom<&str> for FixedString<N> {
+    type Error = ProgramError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        FixedString::new(value)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FixedString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Ok(value) => value.fmt(f),
+            Err(_) => self.as_bytes().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use {
+    crate::{PodU16, Unaligned},
+    bytemuck::{Pod, Zeroable},
+    std::{
+        fmt,
+        ops::{Deref, DerefMut},
+    },
+    typhoon_program::program_error::ProgramError,
+};
+
+/// A list of at most `N` items, stored with its length.
+///
+/// The items are aligned on 1 byte to not need padding after the length, so use the `Pod*`
+/// integers instead of the primitive ones.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FixedVec<T, const N: usize>
+where
+    T: Unaligned,
+{
+    len: PodU16,
+    items: [T; N],
+}
+
+unsafe impl<T, const N: usize> Zeroable for FixedVec<T, N> where T: Unaligned {}
+unsafe impl<T, const N: usize> Pod for FixedVec<T, N> where T: Unaligned {}
+unsafe impl<T, const N: usize> Unaligned for FixedVec<T, N> where T: Unaligned {}
+
+impl<T, const N: usize> FixedVec<T, N>
+where
+    T: Unaligned,
+{
+    pub fn new() -> Self {
+        Self::zeroed()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    pub fn len(&self) -> usize {
+        (self.len.get() as usize).min(N)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items[..self.len()]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        &mut self.items[..len]
+    }
+
+    /// Appends `item`, failing if the list is full.
+    pub fn push(&mut self, item: T) -> Result<(), ProgramError> {
+        let len = self.len();
+        if len == N || len == u16::MAX as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.items[len] = item;
+        self.len = PodU16::new(len as u16 + 1);
+
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        let item = std::mem::replace(&mut self.items[len], T::zeroed());
+        self.len = PodU16::new(len as u16);
+
+        Some(item)
+    }
+
+    /// Removes the item at `index` by shifting the following ones.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+
+        let item = self.items[index];
+        self.items.copy_within(index + 1..len, index);
+        self.pop();
+
+        Some(item)
+    }
+
+    /// Removes the item at `index` by moving the last one in its place.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        let len = self.len();
+        if index >= len {
+            return None;
+        }
+
+        self.items.swap(index, len - 1);
+        self.pop()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::zeroed();
+    }
+}
+
+impl<T, const N: usize> Default for FixedVec<T, N>
+where
+    T: Unaligned,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for FixedVec<T, N>
+where
+    T: Unaligned,
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for FixedVec<T, N>
+where
+    T: Unaligned,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> PartialEq for FixedVec<T, N>
+where
+    T: Unaligned + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for FixedVec<T, N>
+where
+    T: Unaligned + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
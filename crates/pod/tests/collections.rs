@@ -0,0 +1,211 @@
+//! Random operations applied to the collections and to a `std` model, comparing them after each
+//! step.
+
+use {
+    std::collections::{BTreeMap, HashMap, VecDeque},
+    typhoon_pod::collections::{Bitmap, CritbitTree, RingBuffer, Slab},
+};
+
+const ROUNDS: usize = 10_000;
+
+/// Deterministic xorshift generator so failures can be replayed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+/// Zeroed data aligned on 8 bytes.
+fn zeroed(len: usize) -> Vec<u64> {
+    vec![0; len.div_ceil(8)]
+}
+
+fn bytes(data: &mut [u64], len: usize) -> &mut [u8] {
+    &mut bytemuck::cast_slice_mut(data)[..len]
+}
+
+#[test]
+fn ring_buffer() {
+    let mut rng = Rng(0x5eed_0001);
+    let space = RingBuffer::<u64>::space(13);
+    let mut data = zeroed(space);
+    let mut model = VecDeque::new();
+
+    for _ in 0..ROUNDS {
+        // Reload every round to check the state is only kept in the data
+        let mut ring = RingBuffer::<u64>::load(bytes(&mut data, space)).unwrap();
+        assert_eq!(ring.capacity(), 13);
+
+        match rng.below(4) {
+            0 => {
+                let item = rng.next();
+                let result = ring.push_back(item);
+                if model.len() < 13 {
+                    result.unwrap();
+                    model.push_back(item);
+                } else {
+                    assert!(result.is_err());
+                }
+            }
+            1 => {
+                let item = rng.next();
+                let evicted = ring.push_back_overwrite(item).unwrap();
+                let expected = (model.len() == 13).then(|| model.pop_front().unwrap());
+                model.push_back(item);
+                assert_eq!(evicted, expected);
+            }
+            2 => assert_eq!(ring.pop_front(), model.pop_front()),
+            _ => {
+                let index = rng.below(14) as usize;
+                if let Some(item) = ring.get_mut(index) {
+                    *item += 1;
+                }
+                if let Some(item) = model.get_mut(index) {
+                    *item += 1;
+                }
+            }
+        }
+
+        assert_eq!(ring.len(), model.len());
+        assert_eq!(ring.is_full(), model.len() == 13);
+        assert_eq!(ring.front(), model.front());
+        assert_eq!(ring.back(), model.back());
+        assert!(ring.iter().eq(model.iter()));
+    }
+}
+
+#[test]
+fn slab() {
+    let mut rng = Rng(0x5eed_0002);
+    let space = Slab::<[u64; 3]>::space(17);
+    let mut data = zeroed(space);
+    let mut model = HashMap::new();
+
+    for _ in 0..ROUNDS {
+        let mut slab = Slab::<[u64; 3]>::load(bytes(&mut data, space)).unwrap();
+        assert_eq!(slab.capacity(), 17);
+
+        if rng.below(2) == 0 {
+            let item = [rng.next(); 3];
+            match slab.insert(item) {
+                Ok(index) => {
+                    assert!(model.insert(index, item).is_none());
+                }
+                Err(_) => assert_eq!(model.len(), 17),
+            }
+        } else {
+            let index = rng.below(18) as u32;
+            assert_eq!(slab.remove(index), model.remove(&index));
+        }
+
+        assert_eq!(slab.len(), model.len());
+        assert_eq!(slab.remaining(), 17 - model.len());
+        for index in 0..18 {
+            assert_eq!(slab.get(index), model.get(&index));
+        }
+        assert_eq!(slab.iter().count(), model.len());
+    }
+}
+
+#[test]
+fn critbit_tree() {
+    let mut rng = Rng(0x5eed_0003);
+    let space = CritbitTree::<u64>::space(31);
+    let mut data = zeroed(space);
+    let mut model = BTreeMap::new();
+
+    for _ in 0..ROUNDS {
+        let mut tree = CritbitTree::<u64>::load(bytes(&mut data, space)).unwrap();
+        assert_eq!(tree.capacity(), 31);
+
+        // Few distinct keys so insertions overwrite and removals hit, spread over the whole range
+        let key = (rng.below(48) as u128).rotate_right(rng.below(4) as u32 * 37);
+        match rng.below(3) {
+            0 | 1 => {
+                let value = rng.next();
+                match tree.insert(key, value) {
+                    Ok(previous) => assert_eq!(previous, model.insert(key, value)),
+                    Err(_) => {
+                        assert_eq!(model.len(), 31);
+                        assert!(!model.contains_key(&key));
+                    }
+                }
+            }
+            _ => assert_eq!(tree.remove(key).unwrap(), model.remove(&key)),
+        }
+
+        assert_eq!(tree.len(), model.len());
+        assert_eq!(tree.get(key).unwrap(), model.get(&key));
+        assert_eq!(
+            tree.first().unwrap(),
+            model.first_key_value().map(|(k, v)| (*k, v))
+        );
+        assert_eq!(
+            tree.last().unwrap(),
+            model.last_key_value().map(|(k, v)| (*k, v))
+        );
+        assert!(tree.iter().eq(model.iter().map(|(k, v)| (*k, v))));
+    }
+}
+
+#[test]
+fn critbit_tree_too_small() {
+    let mut data = zeroed(CritbitTree::<u64>::space(1));
+    let mut tree =
+        CritbitTree::<u64>::load(bytes(&mut data, CritbitTree::<u64>::space(1))).unwrap();
+
+    assert_eq!(tree.insert(1, 1).unwrap(), None);
+    assert_eq!(tree.insert(1, 2).unwrap(), Some(1));
+    assert!(tree.insert(2, 2).is_err());
+    assert_eq!(tree.len(), 1);
+    assert_eq!(tree.get(1).unwrap(), Some(&2));
+}
+
+#[test]
+fn bitmap() {
+    let mut rng = Rng(0x5eed_0004);
+    let mut data = vec![0; Bitmap::space(104)];
+    let mut model = [false; 104];
+
+    for _ in 0..ROUNDS {
+        let mut bitmap = Bitmap::load(&mut data);
+        let index = rng.below(105) as usize;
+
+        match rng.below(3) {
+            0 => {
+                let value = rng.below(2) == 0;
+                let result = bitmap.set(index, value);
+                match model.get_mut(index) {
+                    Some(bit) => {
+                        result.unwrap();
+                        *bit = value;
+                    }
+                    None => assert!(result.is_err()),
+                }
+            }
+            1 => {
+                if let Ok(value) = bitmap.toggle(index) {
+                    model[index] = !model[index];
+                    assert_eq!(value, model[index]);
+                }
+            }
+            _ => assert_eq!(bitmap.get(index), model.get(index).copied()),
+        }
+
+        assert_eq!(
+            bitmap.count_ones(),
+            model.iter().filter(|bit| **bit).count()
+        );
+        assert_eq!(bitmap.first_one(), model.iter().position(|bit| *bit));
+        assert_eq!(bitmap.first_zero(), model.iter().position(|bit| !*bit));
+    }
+}
@@ -0,0 +1,95 @@
+use typhoon_pod::{FixedString, FixedVec, PodOption, PodU64};
+
+#[test]
+fn pod_option() {
+    let mut option = PodOption::<u64>::none();
+    assert!(option.is_none());
+    assert_eq!(option.get(), None);
+    assert_eq!(option, PodOption::default());
+
+    option.set(Some(u64::MAX));
+    assert!(option.is_some());
+    assert_eq!(option.get(), Some(u64::MAX));
+    assert_eq!(Option::from(option), Some(u64::MAX));
+
+    // The value is zeroed with the flag
+    option.set(None);
+    assert_eq!(option.get(), None);
+    assert_eq!(bytemuck::bytes_of(&option), [0; 9]);
+
+    // Packed, so it never needs padding
+    assert_eq!(core::mem::align_of::<PodOption<u64>>(), 1);
+    assert_eq!(core::mem::size_of::<PodOption<u64>>(), 9);
+}
+
+#[test]
+fn fixed_string() {
+    let mut string = FixedString::<4>::new("").unwrap();
+    assert!(string.is_empty());
+    assert_eq!(string.as_str(), Ok(""));
+
+    string.set("full").unwrap();
+    assert_eq!(string.len(), string.capacity());
+    assert_eq!(string.as_str(), Ok("full"));
+
+    // Past the capacity, the content is left as is
+    assert!(string.set("fuller").is_err());
+    assert!(FixedString::<4>::new("fuller").is_err());
+    assert_eq!(string.as_str(), Ok("full"));
+
+    // A shorter value clears the remaining bytes
+    string.set("ab").unwrap();
+    assert_eq!(string.as_bytes(), b"ab");
+    assert_eq!(&bytemuck::bytes_of(&string)[2..], b"ab\0\0");
+    assert_eq!(string, FixedString::try_from("ab").unwrap());
+
+    // A length past the capacity is clamped and invalid UTF-8 is rejected
+    let mut bytes = [0u8; 6];
+    bytes[0] = u8::MAX;
+    bytes[2] = 0xff;
+    let string: FixedString<4> = bytemuck::cast(bytes);
+    assert_eq!(string.len(), 4);
+    assert!(string.as_str().is_err());
+}
+
+#[test]
+fn fixed_vec() {
+    let mut vec = FixedVec::<PodU64, 3>::new();
+    assert!(vec.is_empty());
+    assert_eq!(vec.pop(), None);
+    assert_eq!(vec.remove(0), None);
+    assert_eq!(vec.swap_remove(0), None);
+
+    for i in 1..=3 {
+        vec.push(PodU64::new(i)).unwrap();
+    }
+    assert!(vec.is_full());
+    assert!(vec.push(PodU64::new(4)).is_err());
+    assert_eq!(vec.as_slice(), [1, 2, 3].map(PodU64::new));
+
+    // Out of bounds
+    assert_eq!(vec.remove(3), None);
+    assert_eq!(vec.swap_remove(3), None);
+
+    // First and last items
+    assert_eq!(vec.remove(0), Some(PodU64::new(1)));
+    assert_eq!(vec.as_slice(), [2, 3].map(PodU64::new));
+    assert_eq!(vec.remove(1), Some(PodU64::new(3)));
+    assert_eq!(vec.as_slice(), [PodU64::new(2)]);
+
+    vec.push(PodU64::new(4)).unwrap();
+    vec.push(PodU64::new(5)).unwrap();
+    assert_eq!(vec.swap_remove(0), Some(PodU64::new(2)));
+    assert_eq!(vec.as_slice(), [5, 4].map(PodU64::new));
+
+    assert_eq!(vec.pop(), Some(PodU64::new(4)));
+    assert_eq!(vec.pop(), Some(PodU64::new(5)));
+    assert_eq!(vec.pop(), None);
+
+    // Removed items are zeroed
+    assert_eq!(bytemuck::bytes_of(&vec), [0; 2 + 3 * 8]);
+
+    vec.push(PodU64::new(6)).unwrap();
+    vec.clear();
+    assert!(vec.is_empty());
+}
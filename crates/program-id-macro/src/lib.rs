@@ -1,13 +1,18 @@
 use {
     cargo_manifest::Manifest,
+    curve25519_dalek::edwards::CompressedEdwardsY,
     heck::ToUpperCamelCase,
     proc_macro::TokenStream,
     proc_macro2::Span,
     quote::{quote, ToTokens},
+    sha2::{Digest, Sha256},
     std::env::var,
     syn::{parse::Parse, parse_macro_input, Ident, LitStr},
 };
 
+/// Same as `typhoon_traits::EVENT_AUTHORITY_SEED`, the macro can't depend on the runtime crates.
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
 #[proc_macro]
 pub fn program_id(item: TokenStream) -> TokenStream {
     parse_macro_input!(item as ProgramId)
@@ -18,16 +23,26 @@ pub fn program_id(item: TokenStream) -> TokenStream {
 struct ProgramId {
     pub name: Ident,
     pub id: String,
+    pub event_authority: ([u8; 32], u8),
 }
 
 impl Parse for ProgramId {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let id: LitStr = input.parse()?;
+        let bytes: [u8; 32] = bs58::decode(id.value())
+            .into_vec()
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(syn::Error::new(id.span(), "Invalid program id."))?;
+        let event_authority = find_program_address(&[EVENT_AUTHORITY_SEED], &bytes).ok_or(
+            syn::Error::new(id.span(), "No event authority for this program id."),
+        )?;
         let name = generate_name()?;
 
         Ok(ProgramId {
             id: id.value(),
             name,
+            event_authority,
         })
     }
 }
@@ -36,10 +51,15 @@ impl ToTokens for ProgramId {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let id = &self.id;
         let name = &self.name;
+        let (event_authority, bump) = &self.event_authority;
 
         quote! {
             program::declare_id!(#id);
 
+            /// The PDA signing the `emit_cpi!` self CPIs and its bump, derived at compile time.
+            pub const EVENT_AUTHORITY: (program::pubkey::Pubkey, u8) =
+                (program::pubkey_from_array([#(#event_authority),*]), #bump);
+
             pub struct #name;
 
             impl ProgramId for #name {
@@ -50,6 +70,24 @@ impl ToTokens for ProgramId {
     }
 }
 
+/// Same derivation as the runtime, done once by the macro instead of in each instruction.
+fn find_program_address(seeds: &[&[u8]], program_id: &[u8; 32]) -> Option<([u8; 32], u8)> {
+    (0..=u8::MAX).rev().find_map(|bump| {
+        let mut hasher = Sha256::new();
+        seeds.iter().for_each(|seed| hasher.update(seed));
+        hasher.update([bump]);
+        hasher.update(program_id);
+        hasher.update(b"ProgramDerivedAddress");
+        let address: [u8; 32] = hasher.finalize().into();
+
+        // A PDA must not be a valid public key
+        CompressedEdwardsY(address)
+            .decompress()
+            .is_none()
+            .then_some((address, bump))
+    })
+}
+
 fn get_cargo_toml() -> syn::Result<String> {
     let crate_dir = var("CARGO_MANIFEST_DIR")
         .map_err(|_| syn::Error::new(Span::call_site(), "Not in valid rust project."))?;
@@ -1,11 +1,14 @@
-/// Re-interprets `&[u8]` as `&T`. The type is already 8 bytes aligned
+/// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Failure
 ///
+/// * If the slice isn't aligned for the new type
 /// * If the slice's length isn’t exactly the size of the new type
 #[inline]
 pub fn try_from_bytes<T: Copy>(s: &[u8]) -> Option<&T> {
-    if s.len() != std::mem::size_of::<T>() {
+    if s.len() != std::mem::size_of::<T>()
+        || s.as_ptr().align_offset(std::mem::align_of::<T>()) != 0
+    {
         None
     } else {
         Some(unsafe { &*(s.as_ptr() as *const T) })
@@ -20,9 +23,26 @@ pub fn try_from_bytes<T: Copy>(s: &[u8]) -> Option<&T> {
 /// * If the slice's length isn’t exactly the size of the new type
 #[inline]
 pub fn try_from_bytes_mut<T: Copy>(s: &mut [u8]) -> Option<&mut T> {
-    if s.len() != std::mem::size_of::<T>() {
+    if s.len() != std::mem::size_of::<T>()
+        || s.as_ptr().align_offset(std::mem::align_of::<T>()) != 0
+    {
         None
     } else {
         Some(unsafe { &mut *(s.as_mut_ptr() as *mut T) })
     }
 }
+
+/// `Pod` types aligned on 1 byte, which never need padding before them.
+///
+/// # Safety
+///
+/// The alignment of the type must be 1.
+pub unsafe trait Unaligned: bytemuck::Pod {}
+
+unsafe impl Unaligned for u8 {}
+unsafe impl Unaligned for i8 {}
+unsafe impl<T, const N: usize> Unaligned for [T; N] where T: Unaligned {}
+
+// The pinocchio public key is already a byte array, covered above
+#[cfg(not(feature = "pinocchio"))]
+unsafe impl Unaligned for crate::pubkey::Pubkey {}
@@ -0,0 +1,126 @@
+use crate::{pubkey::Pubkey, ProgramResult, RawAccountInfo, SignerSeeds};
+
+/// An account passed to a cross-program invocation with its privileges.
+#[derive(Clone, Copy)]
+pub struct CpiAccount<'a> {
+    pub info: &'a RawAccountInfo,
+    pub is_writable: bool,
+    pub is_signer: bool,
+}
+
+impl<'a> CpiAccount<'a> {
+    pub fn new(
+        info: &'a (impl AsRef<RawAccountInfo> + ?Sized),
+        is_writable: bool,
+        is_signer: bool,
+    ) -> Self {
+        CpiAccount {
+            info: info.as_ref(),
+            is_writable,
+            is_signer,
+        }
+    }
+
+    pub fn readonly(info: &'a (impl AsRef<RawAccountInfo> + ?Sized)) -> Self {
+        Self::new(info, false, false)
+    }
+
+    pub fn writable(info: &'a (impl AsRef<RawAccountInfo> + ?Sized)) -> Self {
+        Self::new(info, true, false)
+    }
+
+    pub fn readonly_signer(info: &'a (impl AsRef<RawAccountInfo> + ?Sized)) -> Self {
+        Self::new(info, false, true)
+    }
+
+    pub fn writable_signer(info: &'a (impl AsRef<RawAccountInfo> + ?Sized)) -> Self {
+        Self::new(info, true, true)
+    }
+}
+
+/// A cross-program invocation, built the same way for every backend.
+///
+/// ```ignore
+/// CpiInstruction {
+///     program_id: &system_program::ID,
+///     accounts: [CpiAccount::writable_signer(&from), CpiAccount::writable(&to)],
+///     data: &data,
+/// }
+/// .invoke_signed(&[signer!(b"vault", &[bump])])?;
+/// ```
+pub struct CpiInstruction<'a, 'b, const N: usize> {
+    pub program_id: &'a Pubkey,
+    pub accounts: [CpiAccount<'a>; N],
+    pub data: &'b [u8],
+}
+
+impl<const N: usize> CpiInstruction<'_, '_, N> {
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    #[cfg(not(feature = "pinocchio"))]
+    pub fn invoke_signed(&self, signers: &[SignerSeeds]) -> ProgramResult {
+        use {
+            crate::{Account, Instruction},
+            solana_nostd_entrypoint::AccountMetaC,
+        };
+
+        let metas: [AccountMetaC; N] = core::array::from_fn(|i| AccountMetaC {
+            pubkey: self.accounts[i].info.key(),
+            is_writable: self.accounts[i].is_writable,
+            is_signer: self.accounts[i].is_signer,
+        });
+        let infos: [Account; N] = core::array::from_fn(|i| self.accounts[i].info.to_info_c());
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: metas.as_ptr(),
+            accounts_len: N as u64,
+            data: self.data.as_ptr(),
+            data_len: self.data.len() as u64,
+        };
+
+        #[cfg(target_os = "solana")]
+        {
+            let result = unsafe {
+                crate::syscalls::sol_invoke_signed_c(
+                    &instruction as *const Instruction as *const u8,
+                    infos.as_ptr() as *const u8,
+                    N as u64,
+                    signers.as_ptr() as *const u8,
+                    signers.len() as u64,
+                )
+            };
+
+            if result != 0 {
+                return Err(result.into());
+            }
+        }
+
+        #[cfg(not(target_os = "solana"))]
+        core::hint::black_box((&instruction, &infos, signers));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "pinocchio")]
+    pub fn invoke_signed(&self, signers: &[SignerSeeds]) -> ProgramResult {
+        use crate::instruction::{AccountMeta, Instruction};
+
+        let metas: [AccountMeta; N] = core::array::from_fn(|i| {
+            AccountMeta::new(
+                self.accounts[i].info.key(),
+                self.accounts[i].is_writable,
+                self.accounts[i].is_signer,
+            )
+        });
+        let infos: [&RawAccountInfo; N] = core::array::from_fn(|i| self.accounts[i].info);
+        let instruction = Instruction {
+            program_id: self.program_id,
+            accounts: &metas,
+            data: self.data,
+        };
+
+        crate::program::invoke_signed(&instruction, &infos, signers)
+    }
+}
@@ -0,0 +1,78 @@
+//! Operations on the alt_bn128 curve, with the encoding of the Ethereum precompiles.
+//!
+//! Points are big-endian, 64 bytes in G1 and 128 bytes in G2.
+
+use crate::program_error::ProgramError;
+
+pub const G1_POINT_SIZE: usize = 64;
+pub const G2_POINT_SIZE: usize = 128;
+/// Two G1 points.
+pub const ADDITION_INPUT_LEN: usize = 128;
+/// A G1 point and a 32 bytes scalar.
+pub const MULTIPLICATION_INPUT_LEN: usize = 96;
+/// A G1 and a G2 point, repeated for each pair.
+pub const PAIRING_ELEMENT_LEN: usize = G1_POINT_SIZE + G2_POINT_SIZE;
+
+#[cfg(target_os = "solana")]
+const ADD: u64 = 0;
+#[cfg(target_os = "solana")]
+const MUL: u64 = 2;
+#[cfg(target_os = "solana")]
+const PAIRING: u64 = 3;
+
+#[cfg(target_os = "solana")]
+fn group_op<const N: usize>(op: u64, input: &[u8]) -> Result<[u8; N], ProgramError> {
+    let mut result = [0u8; N];
+    let status = unsafe {
+        crate::syscalls::sol_alt_bn128_group_op(
+            op,
+            input.as_ptr(),
+            input.len() as u64,
+            result.as_mut_ptr(),
+        )
+    };
+
+    match status {
+        0 => Ok(result),
+        _ => Err(ProgramError::InvalidArgument),
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+fn to_array<const N: usize>(
+    result: Result<Vec<u8>, solana_bn254::AltBn128Error>,
+) -> Result<[u8; N], ProgramError> {
+    result
+        .ok()
+        .and_then(|result| result.try_into().ok())
+        .ok_or(ProgramError::InvalidArgument)
+}
+
+/// Adds two G1 points.
+pub fn addition(input: &[u8]) -> Result<[u8; G1_POINT_SIZE], ProgramError> {
+    #[cfg(target_os = "solana")]
+    return group_op(ADD, input);
+
+    #[cfg(not(target_os = "solana"))]
+    to_array(solana_bn254::prelude::alt_bn128_addition(input))
+}
+
+/// Multiplies a G1 point by a scalar.
+pub fn multiplication(input: &[u8]) -> Result<[u8; G1_POINT_SIZE], ProgramError> {
+    #[cfg(target_os = "solana")]
+    return group_op(MUL, input);
+
+    #[cfg(not(target_os = "solana"))]
+    to_array(solana_bn254::prelude::alt_bn128_multiplication(input))
+}
+
+/// Checks the product of the pairings of each G1 and G2 points is one.
+pub fn pairing(input: &[u8]) -> Result<bool, ProgramError> {
+    #[cfg(target_os = "solana")]
+    let result = group_op::<32>(PAIRING, input)?;
+
+    #[cfg(not(target_os = "solana"))]
+    let result: [u8; 32] = to_array(solana_bn254::prelude::alt_bn128_pairing(input))?;
+
+    Ok(result[31] == 1)
+}
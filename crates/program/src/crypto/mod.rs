@@ -0,0 +1,97 @@
+//! Hashing and cryptography with the same API on both backends.
+//!
+//! On-chain every function is a syscall, off-chain the same algorithms run in pure Rust so
+//! programs can be tested natively.
+
+pub mod alt_bn128;
+pub mod poseidon;
+
+pub const HASH_BYTES: usize = 32;
+
+/// Hashes the concatenation of `vals` with SHA-256.
+#[inline(always)]
+pub fn sha256(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; HASH_BYTES];
+        unsafe {
+            crate::syscalls::sol_sha256(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr(),
+            );
+        }
+
+        hash
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        for val in vals {
+            hasher.update(val);
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// Hashes the concatenation of `vals` with Keccak-256, as used by Ethereum.
+#[inline(always)]
+pub fn keccak256(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; HASH_BYTES];
+        unsafe {
+            crate::syscalls::sol_keccak256(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr(),
+            );
+        }
+
+        hash
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        use sha3::Digest;
+
+        let mut hasher = sha3::Keccak256::new();
+        for val in vals {
+            hasher.update(val);
+        }
+
+        hasher.finalize().into()
+    }
+}
+
+/// Hashes the concatenation of `vals` with BLAKE3.
+#[inline(always)]
+pub fn blake3(vals: &[&[u8]]) -> [u8; HASH_BYTES] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; HASH_BYTES];
+        unsafe {
+            crate::syscalls::sol_blake3(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr(),
+            );
+        }
+
+        hash
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let mut hasher = blake3::Hasher::new();
+        for val in vals {
+            hasher.update(val);
+        }
+
+        hasher.finalize().into()
+    }
+}
@@ -0,0 +1,61 @@
+//! Poseidon hashes over the BN254 curve, compatible with circom.
+
+use crate::program_error::ProgramError;
+
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parameters {
+    /// BN254 scalar field, x^5 S-boxes, with up to 12 inputs.
+    Bn254X5 = 0,
+}
+
+/// Byte order of the inputs and of the hash.
+#[repr(u64)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    BigEndian = 0,
+    LittleEndian = 1,
+}
+
+/// Hashes `vals`, each being a 32 bytes field element.
+///
+/// Fails with `InvalidArgument` if there are too many inputs or one is not in the field.
+pub fn hashv(
+    parameters: Parameters,
+    endianness: Endianness,
+    vals: &[&[u8]],
+) -> Result<[u8; 32], ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; 32];
+        let result = unsafe {
+            crate::syscalls::sol_poseidon(
+                parameters as u64,
+                endianness as u64,
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr(),
+            )
+        };
+
+        match result {
+            0 => Ok(hash),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let parameters = match parameters {
+            Parameters::Bn254X5 => solana_poseidon::Parameters::Bn254X5,
+        };
+        let endianness = match endianness {
+            Endianness::BigEndian => solana_poseidon::Endianness::BigEndian,
+            Endianness::LittleEndian => solana_poseidon::Endianness::LittleEndian,
+        };
+
+        solana_poseidon::hashv(parameters, endianness, vals)
+            .map(|hash| hash.to_bytes())
+            .map_err(|_| ProgramError::InvalidArgument)
+    }
+}
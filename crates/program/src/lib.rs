@@ -11,3 +11,7 @@ mod pinocchio;
 pub use pinocchio::*;
 
 pub mod bytes;
+pub mod cpi;
+pub mod crypto;
+pub mod secp256k1;
+pub mod sysvars;
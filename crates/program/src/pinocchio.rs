@@ -10,6 +10,24 @@ pub type SignerSeeds<'a, 'b> = instruction::Signer<'a, 'b>;
 
 pub use pinocchio_pubkey::declare_id;
 
+/// Logs a public key in base58.
+#[inline(always)]
+pub fn log_pubkey(key: &pubkey::Pubkey) {
+    pubkey::log(key);
+}
+
+/// Builds a public key from its bytes, usable in constants.
+#[inline(always)]
+pub const fn pubkey_from_array(bytes: [u8; 32]) -> pubkey::Pubkey {
+    bytes
+}
+
+/// Finds a valid program derived address and its bump seed.
+#[inline(always)]
+pub fn find_program_address(seeds: &[&[u8]], program_id: &pubkey::Pubkey) -> (pubkey::Pubkey, u8) {
+    pubkey::find_program_address(seeds, program_id)
+}
+
 #[macro_export]
 macro_rules! program_entrypoint {
     ($name: ident) => {
@@ -0,0 +1,57 @@
+//! Recovery of secp256k1 public keys, to check Ethereum signatures.
+
+use crate::{crypto::keccak256, program_error::ProgramError};
+
+/// Length of an uncompressed public key, without its `0x04` prefix.
+pub const PUBKEY_LEN: usize = 64;
+pub const SIGNATURE_LEN: usize = 64;
+pub const ETH_ADDRESS_LEN: usize = 20;
+
+/// Recovers the public key which signed `hash` with the `sol_secp256k1_recover` syscall.
+///
+/// The syscall is only available on-chain, `InvalidArgument` is returned otherwise.
+pub fn recover(
+    hash: &[u8; 32],
+    recovery_id: u8,
+    signature: &[u8; SIGNATURE_LEN],
+) -> Result<[u8; PUBKEY_LEN], ProgramError> {
+    #[cfg(target_os = "solana")]
+    {
+        let mut pubkey = [0u8; PUBKEY_LEN];
+        let result = unsafe {
+            crate::syscalls::sol_secp256k1_recover(
+                hash.as_ptr(),
+                recovery_id as u64,
+                signature.as_ptr(),
+                pubkey.as_mut_ptr(),
+            )
+        };
+
+        match result {
+            0 => Ok(pubkey),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    {
+        let _ = (hash, recovery_id, signature);
+        Err(ProgramError::InvalidArgument)
+    }
+}
+
+/// Recovers the Ethereum address which signed `hash`, the last bytes of the keccak hash of the
+/// public key.
+pub fn recover_eth_address(
+    hash: &[u8; 32],
+    recovery_id: u8,
+    signature: &[u8; SIGNATURE_LEN],
+) -> Result<[u8; ETH_ADDRESS_LEN], ProgramError> {
+    let pubkey = recover(hash, recovery_id, signature)?;
+
+    let pubkey_hash = keccak256(&[&pubkey]);
+    let mut address = [0u8; ETH_ADDRESS_LEN];
+    address.copy_from_slice(&pubkey_hash[32 - ETH_ADDRESS_LEN..]);
+
+    Ok(address)
+}
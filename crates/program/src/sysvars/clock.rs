@@ -0,0 +1,36 @@
+use {
+    super::{get_sysvar, SysvarId},
+    crate::{program_error::ProgramError, pubkey::Pubkey, pubkey_from_array},
+};
+
+/// `SysvarC1ock11111111111111111111111111111111`
+pub const ID: Pubkey = pubkey_from_array([
+    6, 167, 213, 23, 24, 199, 116, 201, 40, 86, 99, 152, 105, 29, 94, 182, 139, 94, 184, 163, 155,
+    75, 109, 92, 115, 85, 91, 33, 0, 0, 0, 0,
+]);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Clock {
+    pub slot: u64,
+    /// Timestamp of the first slot of the epoch.
+    pub epoch_start_timestamp: i64,
+    pub epoch: u64,
+    /// Future epoch for which the leader schedule has been computed.
+    pub leader_schedule_epoch: u64,
+    /// Estimated unix timestamp of the slot, in seconds.
+    pub unix_timestamp: i64,
+}
+
+impl SysvarId for Clock {
+    const ID: Pubkey = ID;
+}
+
+impl Clock {
+    pub fn get() -> Result<Self, ProgramError> {
+        let mut clock = Clock::default();
+        get_sysvar!(&mut clock, sol_get_clock_sysvar)?;
+
+        Ok(clock)
+    }
+}
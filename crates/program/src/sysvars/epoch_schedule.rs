@@ -0,0 +1,95 @@
+use {
+    super::{get_sysvar, SysvarId},
+    crate::{program_error::ProgramError, pubkey::Pubkey, pubkey_from_array},
+};
+
+/// `SysvarEpochSchedu1e111111111111111111111111`
+pub const ID: Pubkey = pubkey_from_array([
+    6, 167, 213, 23, 24, 220, 63, 238, 2, 211, 228, 127, 1, 0, 248, 176, 84, 247, 148, 46, 96, 89,
+    30, 63, 80, 135, 25, 168, 5, 0, 0, 0,
+]);
+
+/// Length of the first epoch when warmup is enabled.
+pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Packed like the data of the sysvar account.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EpochSchedule {
+    pub slots_per_epoch: u64,
+    /// How many slots before the start of an epoch its leader schedule is computed.
+    pub leader_schedule_slot_offset: u64,
+    /// Whether epochs start short and double in length until `slots_per_epoch`.
+    pub warmup: bool,
+    pub first_normal_epoch: u64,
+    pub first_normal_slot: u64,
+}
+
+impl SysvarId for EpochSchedule {
+    const ID: Pubkey = ID;
+}
+
+impl EpochSchedule {
+    pub fn get() -> Result<Self, ProgramError> {
+        // The syscall writes the unpacked `#[repr(C)]` layout.
+        let mut schedule = [0u64; 5];
+        get_sysvar!(&mut schedule, sol_get_epoch_schedule_sysvar)?;
+
+        Ok(EpochSchedule {
+            slots_per_epoch: schedule[0],
+            leader_schedule_slot_offset: schedule[1],
+            warmup: schedule[2] as u8 != 0,
+            first_normal_epoch: schedule[3],
+            first_normal_slot: schedule[4],
+        })
+    }
+
+    pub fn get_epoch(&self, slot: u64) -> u64 {
+        self.get_epoch_and_slot_index(slot).0
+    }
+
+    /// Returns the epoch of `slot` and the index of the slot in that epoch.
+    pub fn get_epoch_and_slot_index(&self, slot: u64) -> (u64, u64) {
+        if slot < self.first_normal_slot {
+            let epoch = slot
+                .saturating_add(MINIMUM_SLOTS_PER_EPOCH)
+                .saturating_add(1)
+                .next_power_of_two()
+                .trailing_zeros()
+                .saturating_sub(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros())
+                .saturating_sub(1);
+
+            let epoch_len =
+                2u64.saturating_pow(epoch.saturating_add(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros()));
+
+            (
+                u64::from(epoch),
+                slot.saturating_sub(epoch_len.saturating_sub(MINIMUM_SLOTS_PER_EPOCH)),
+            )
+        } else {
+            let normal_slot_index = slot.saturating_sub(self.first_normal_slot);
+            let normal_epoch_index = normal_slot_index
+                .checked_div(self.slots_per_epoch)
+                .unwrap_or(0);
+            let epoch = self.first_normal_epoch.saturating_add(normal_epoch_index);
+            let slot_index = normal_slot_index
+                .checked_rem(self.slots_per_epoch)
+                .unwrap_or(0);
+
+            (epoch, slot_index)
+        }
+    }
+
+    pub fn get_first_slot_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch <= self.first_normal_epoch {
+            2u64.saturating_pow(epoch as u32)
+                .saturating_sub(1)
+                .saturating_mul(MINIMUM_SLOTS_PER_EPOCH)
+        } else {
+            epoch
+                .saturating_sub(self.first_normal_epoch)
+                .saturating_mul(self.slots_per_epoch)
+                .saturating_add(self.first_normal_slot)
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    bytes::try_from_bytes, program_error::ProgramError, pubkey::Pubkey, pubkey_from_array,
+};
+
+/// `Sysvar1nstructions1111111111111111111111111`
+pub const ID: Pubkey = pubkey_from_array([
+    6, 167, 213, 23, 24, 123, 209, 102, 53, 218, 212, 4, 85, 253, 194, 192, 193, 36, 198, 143, 33,
+    86, 117, 165, 219, 186, 203, 95, 8, 0, 0, 0,
+]);
+
+/// Stack height of an instruction invoked by the transaction, CPIs being above.
+pub const TRANSACTION_LEVEL_STACK_HEIGHT: u64 = 1;
+
+const IS_SIGNER: u8 = 1 << 0;
+const IS_WRITABLE: u8 = 1 << 1;
+
+/// Returns the height of the current invocation, `0` off-chain.
+#[inline(always)]
+pub fn get_stack_height() -> u64 {
+    #[cfg(target_os = "solana")]
+    unsafe {
+        crate::syscalls::sol_get_stack_height()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    0
+}
+
+/// Returns `true` when the current program is invoked by the transaction and not through a CPI.
+#[inline(always)]
+pub fn is_top_level() -> bool {
+    get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
+/// Zero-copy view of the data of the instructions sysvar.
+///
+/// The data starts with the number of instructions and their offsets, and ends with the index of
+/// the instruction being executed.
+#[derive(Clone, Copy)]
+pub struct Instructions<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Instructions<'a> {
+    pub fn new(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let instructions = Instructions { data };
+
+        let header_len = 2 + 2 * instructions.len()?;
+        if data.len() < header_len + 2 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(instructions)
+    }
+
+    /// Number of top-level instructions of the transaction.
+    pub fn len(&self) -> Result<usize, ProgramError> {
+        read_u16(self.data, 0).map(usize::from)
+    }
+
+    pub fn is_empty(&self) -> Result<bool, ProgramError> {
+        self.len().map(|len| len == 0)
+    }
+
+    /// Index of the top-level instruction being executed.
+    pub fn current_index(&self) -> Result<u16, ProgramError> {
+        read_u16(self.data, self.data.len() - 2)
+    }
+
+    pub fn load_instruction_at(
+        &self,
+        index: usize,
+    ) -> Result<IntrospectedInstruction<'a>, ProgramError> {
+        if index >= self.len()? {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let offset = read_u16(self.data, 2 + 2 * index)? as usize;
+        IntrospectedInstruction::parse(
+            self.data
+                .get(offset..)
+                .ok_or(ProgramError::InvalidAccountData)?,
+        )
+    }
+
+    pub fn load_current_instruction(&self) -> Result<IntrospectedInstruction<'a>, ProgramError> {
+        self.load_instruction_at(self.current_index()? as usize)
+    }
+
+    /// Loads the instruction at `offset` from the current one, negative offsets being before it.
+    pub fn get_instruction_relative(
+        &self,
+        offset: i64,
+    ) -> Result<IntrospectedInstruction<'a>, ProgramError> {
+        let index = (self.current_index()? as i64)
+            .checked_add(offset)
+            .and_then(|index| usize::try_from(index).ok())
+            .ok_or(ProgramError::InvalidArgument)?;
+
+        self.load_instruction_at(index)
+    }
+}
+
+/// A top-level instruction of the transaction, borrowed from the sysvar data.
+#[derive(Clone, Copy)]
+pub struct IntrospectedInstruction<'a> {
+    program_id: &'a Pubkey,
+    accounts: &'a [IntrospectedAccountMeta],
+    data: &'a [u8],
+}
+
+impl<'a> IntrospectedInstruction<'a> {
+    /// Parses an instruction laid out as its accounts, program id and data, each list being
+    /// prefixed with its `u16` length.
+    fn parse(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let num_accounts = read_u16(data, 0)? as usize;
+        let accounts_end = 2 + num_accounts * core::mem::size_of::<IntrospectedAccountMeta>();
+        let accounts = data
+            .get(2..accounts_end)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        let program_id = data
+            .get(accounts_end..accounts_end + 32)
+            .and_then(try_from_bytes)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        let data_start = accounts_end + 32 + 2;
+        let data_len = read_u16(data, accounts_end + 32)? as usize;
+        let data = data
+            .get(data_start..data_start + data_len)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Ok(IntrospectedInstruction {
+            program_id,
+            // SAFETY: `IntrospectedAccountMeta` is 1 byte aligned and any value is valid.
+            accounts: unsafe {
+                core::slice::from_raw_parts(
+                    accounts.as_ptr() as *const IntrospectedAccountMeta,
+                    num_accounts,
+                )
+            },
+            data,
+        })
+    }
+
+    pub fn program_id(&self) -> &'a Pubkey {
+        self.program_id
+    }
+
+    pub fn accounts(&self) -> &'a [IntrospectedAccountMeta] {
+        self.accounts
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct IntrospectedAccountMeta {
+    flags: u8,
+    key: Pubkey,
+}
+
+impl IntrospectedAccountMeta {
+    pub fn key(&self) -> &Pubkey {
+        &self.key
+    }
+
+    pub fn is_signer(&self) -> bool {
+        self.flags & IS_SIGNER != 0
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.flags & IS_WRITABLE != 0
+    }
+}
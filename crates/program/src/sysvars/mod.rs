@@ -0,0 +1,39 @@
+//! Sysvars with the same layout and API on both backends.
+//!
+//! `Clock`, `Rent` and `EpochSchedule` are fetched with `get()` through their syscall, while every
+//! sysvar can be read from its account with the `Sysvar` account type of `typhoon-accounts`, or
+//! `InstructionsSysvar` for the instructions of the transaction.
+
+pub mod clock;
+pub mod epoch_schedule;
+pub mod instructions;
+pub mod rent;
+pub mod slot_hashes;
+
+use crate::pubkey::Pubkey;
+
+/// A sysvar and the address of its account.
+pub trait SysvarId {
+    const ID: Pubkey;
+}
+
+/// Copies a sysvar into `$dst` with its syscall, returning `UnsupportedSysvar` off-chain.
+macro_rules! get_sysvar {
+    ($dst:expr, $syscall:ident) => {{
+        #[cfg(target_os = "solana")]
+        let result = match unsafe { $crate::syscalls::$syscall($dst as *mut _ as *mut u8) } {
+            0 => Ok(()),
+            error => Err($crate::program_error::ProgramError::from(error)),
+        };
+
+        #[cfg(not(target_os = "solana"))]
+        let result = {
+            let _ = $dst;
+            Err($crate::program_error::ProgramError::UnsupportedSysvar)
+        };
+
+        result
+    }};
+}
+
+pub(crate) use get_sysvar;
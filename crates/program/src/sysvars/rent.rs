@@ -0,0 +1,53 @@
+use {
+    super::{get_sysvar, SysvarId},
+    crate::{program_error::ProgramError, pubkey::Pubkey, pubkey_from_array},
+};
+
+/// `SysvarRent111111111111111111111111111111111`
+pub const ID: Pubkey = pubkey_from_array([
+    6, 167, 213, 23, 25, 44, 92, 81, 33, 140, 201, 76, 61, 74, 241, 127, 88, 218, 238, 8, 155, 161,
+    253, 68, 227, 219, 217, 138, 0, 0, 0, 0,
+]);
+
+/// Bytes of account metadata counted in the rent of every account.
+pub const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+/// Packed like the data of the sysvar account.
+#[repr(C, packed)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rent {
+    pub lamports_per_byte_year: u64,
+    /// Years of rent an account must hold to be exempt.
+    pub exemption_threshold: f64,
+    /// Percentage of the collected rent which is burned.
+    pub burn_percent: u8,
+}
+
+impl SysvarId for Rent {
+    const ID: Pubkey = ID;
+}
+
+impl Rent {
+    pub fn get() -> Result<Self, ProgramError> {
+        // The syscall writes the unpacked `#[repr(C)]` layout.
+        let mut rent = [0u64; 3];
+        get_sysvar!(&mut rent, sol_get_rent_sysvar)?;
+
+        Ok(Rent {
+            lamports_per_byte_year: rent[0],
+            exemption_threshold: f64::from_bits(rent[1]),
+            burn_percent: rent[2] as u8,
+        })
+    }
+
+    /// Minimum balance for an account with `data_len` bytes to be rent-exempt.
+    pub fn minimum_balance(&self, data_len: usize) -> u64 {
+        let bytes = data_len as u64;
+        (((ACCOUNT_STORAGE_OVERHEAD + bytes) * self.lamports_per_byte_year) as f64
+            * self.exemption_threshold) as u64
+    }
+
+    pub fn is_exempt(&self, lamports: u64, data_len: usize) -> bool {
+        lamports >= self.minimum_balance(data_len)
+    }
+}
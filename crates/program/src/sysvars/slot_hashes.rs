@@ -0,0 +1,68 @@
+use {
+    super::SysvarId,
+    crate::{pubkey::Pubkey, pubkey_from_array},
+};
+
+/// `SysvarS1otHashes111111111111111111111111111`
+pub const ID: Pubkey = pubkey_from_array([
+    6, 167, 213, 23, 25, 47, 10, 175, 198, 242, 101, 227, 251, 119, 204, 122, 218, 130, 197, 41,
+    208, 190, 59, 19, 110, 45, 0, 85, 32, 0, 0, 0,
+]);
+
+/// Header of the sysvar account, followed by `len` entries sorted by descending slot.
+///
+/// It has no syscall, the entries are only available from the account.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SlotHashes {
+    len: [u8; 8],
+}
+
+impl SysvarId for SlotHashes {
+    const ID: Pubkey = ID;
+}
+
+impl SlotHashes {
+    pub fn len(&self) -> usize {
+        u64::from_le_bytes(self.len) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads the entries from the whole data of the sysvar account.
+    pub fn entries(data: &[u8]) -> Option<&[SlotHash]> {
+        let (len, entries) = data.split_first_chunk::<8>()?;
+        let len = u64::from_le_bytes(*len) as usize;
+        let entries = entries.get(..len.checked_mul(core::mem::size_of::<SlotHash>())?)?;
+
+        // SAFETY: `SlotHash` is made of bytes, so it is 1 byte aligned and any value is valid.
+        Some(unsafe { core::slice::from_raw_parts(entries.as_ptr() as *const SlotHash, len) })
+    }
+
+    /// Finds the hash of `slot` in the entries.
+    pub fn get(entries: &[SlotHash], slot: u64) -> Option<&[u8; 32]> {
+        entries
+            .binary_search_by(|entry| slot.cmp(&entry.slot()))
+            .ok()
+            .map(|index| entries[index].hash())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct SlotHash {
+    slot: [u8; 8],
+    hash: [u8; 32],
+}
+
+impl SlotHash {
+    pub fn slot(&self) -> u64 {
+        u64::from_le_bytes(self.slot)
+    }
+
+    pub fn hash(&self) -> &[u8; 32] {
+        &self.hash
+    }
+}
@@ -7,15 +7,41 @@ pub use {
     },
 };
 
-pub mod sysvars {
-    pub use solana_nostd_entrypoint::solana_program::sysvar::*;
-}
-
 pub type RawAccountInfo = solana_nostd_entrypoint::NoStdAccountInfo;
 pub type Account = solana_nostd_entrypoint::AccountInfoC;
 pub type Instruction = solana_nostd_entrypoint::InstructionC;
 pub type SignerSeeds<'a, 'b> = &'a [&'b [u8]];
 
+/// Logs a public key in base58.
+#[inline(always)]
+pub fn log_pubkey(key: &pubkey::Pubkey) {
+    key.log();
+}
+
+/// Builds a public key from its bytes, usable in constants.
+#[inline(always)]
+pub const fn pubkey_from_array(bytes: [u8; 32]) -> pubkey::Pubkey {
+    pubkey::Pubkey::new_from_array(bytes)
+}
+
+/// Finds a valid program derived address and its bump seed.
+#[inline(always)]
+pub fn find_program_address(seeds: &[&[u8]], program_id: &pubkey::Pubkey) -> (pubkey::Pubkey, u8) {
+    pubkey::Pubkey::find_program_address(seeds, program_id)
+}
+
+/// Builds the [`SignerSeeds`] of a PDA, like the `signer!` macro of pinocchio.
+///
+/// ```ignore
+/// invoke_signed(&[signer!(b"vault", &[bump])])?;
+/// ```
+#[macro_export]
+macro_rules! signer {
+    ($($seed:expr),* $(,)?) => {
+        &[$(::core::convert::AsRef::<[u8]>::as_ref($seed)),*]
+    };
+}
+
 #[macro_export]
 macro_rules! program_entrypoint {
     ($name: ident) => {
@@ -0,0 +1,77 @@
+//! Published known-answer vectors for the native fallbacks of the syscalls.
+
+use typhoon_program::crypto::{
+    alt_bn128::addition,
+    blake3, keccak256,
+    poseidon::{hashv, Endianness, Parameters},
+    sha256,
+};
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn sha256_abc() {
+    // FIPS 180-2, appendix B.1
+    assert_eq!(
+        sha256(&[b"a", b"bc"]).to_vec(),
+        hex("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+    );
+}
+
+#[test]
+fn keccak256_abc() {
+    // Keccak-256 as used by Ethereum, not the NIST SHA3-256
+    assert_eq!(
+        keccak256(&[b"a", b"bc"]).to_vec(),
+        hex("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+    );
+}
+
+#[test]
+fn blake3_empty() {
+    // First entry of the BLAKE3 `test_vectors.json`
+    assert_eq!(
+        blake3(&[]).to_vec(),
+        hex("af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")
+    );
+}
+
+#[test]
+fn poseidon_bn254_x5() {
+    // Example of `light-poseidon`, the circom parameters used by the syscall
+    assert_eq!(
+        hashv(
+            Parameters::Bn254X5,
+            Endianness::BigEndian,
+            &[&[1u8; 32], &[2u8; 32]]
+        ),
+        Ok([
+            13, 84, 225, 147, 143, 138, 140, 28, 125, 235, 94, 3, 85, 242, 99, 25, 32, 123, 132,
+            254, 156, 162, 206, 27, 38, 231, 53, 200, 41, 130, 25, 144
+        ])
+    );
+}
+
+#[test]
+fn alt_bn128_addition_chfast1() {
+    // `chfast1` of the Ethereum `bn256Add` precompile tests
+    let input = hex(
+        "18b18acfb4c2c30276db5411368e7185b311dd124691610c5d3b74034e093dc9\
+         063c909c4720840cb5134cb9f59fa749755796819658d32efc0d288198f37266\
+         07c2b7f58a84bd6145f00c9c2bc0bb1a187f20ff2c92963a88019e7c6a014eed\
+         06614e20c147e940f2d70da3f74c9a17df361706a4485c742bd6788478fa17d7",
+    );
+
+    assert_eq!(
+        addition(&input).unwrap().to_vec(),
+        hex(
+            "2243525c5efd4b9c3d3c45ac0ca3fe4dd85e830a4ce6b65fa1eeaee202839703\
+             301d1d33be6da8e509df21cc35964723180eed7532537db9ae5e7d48f195c915"
+        )
+    );
+}
@@ -0,0 +1,233 @@
+use {
+    crate::{Role, RoleRegistry},
+    std::marker::PhantomData,
+    typhoon_accounts::{
+        FromAccountInfo, Mut, Program, ProgramData, ProgramId, ReadableAccount, Signer, Singleton,
+        System, SystemAccount,
+    },
+    typhoon_context::HandlerContext,
+    typhoon_errors::Error,
+    typhoon_program::{program_error::ProgramError, RawAccountInfo},
+};
+
+/// A signer holding the role `R` in the registry of the program `P`, to put before the context of
+/// a handler restricted to it.
+///
+/// Accounts:
+/// * the signer
+/// * the registry, read-only by default or `Mut<Singleton<RoleRegistry<P>>>` to update it
+pub struct Authority<'a, R, P, A = Singleton<'a, RoleRegistry<P>>>
+where
+    R: Role,
+    P: ProgramId,
+    A: ReadableAccount<DataType = RoleRegistry<P>>,
+{
+    pub signer: Signer<'a>,
+    pub registry: A,
+    _role: PhantomData<R>,
+}
+
+impl<'a, R, P, A> HandlerContext<'a> for Authority<'a, R, P, A>
+where
+    R: Role,
+    P: ProgramId,
+    A: FromAccountInfo<'a> + ReadableAccount<DataType = RoleRegistry<P>>,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [signer, registry, rem @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let signer = Signer::try_from_info(signer)?;
+        let registry = A::try_from_info(registry)?;
+
+        if !registry.data()?.has_role::<R>(signer.key()) {
+            return Err(Error::RoleNotGranted.into());
+        }
+
+        *accounts = rem;
+
+        Ok(Authority {
+            signer,
+            registry,
+            _role: PhantomData,
+        })
+    }
+}
+
+/// Creates the registry of the program `P`, the upgrade authority becoming its owner.
+///
+/// Accounts:
+/// * the upgrade authority, paying for the registry
+/// * the `ProgramData` of the program
+/// * the registry, not created yet
+/// * the System program
+pub struct InitRoles<'a, P>
+where
+    P: ProgramId,
+{
+    pub authority: Mut<Signer<'a>>,
+    pub program_data: ProgramData<'a, P>,
+    pub registry: Mut<SystemAccount<'a>>,
+    pub system: Program<'a, System>,
+}
+
+impl<'a, P> HandlerContext<'a> for InitRoles<'a, P>
+where
+    P: ProgramId,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [authority, program_data, registry, system, rem @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let authority = Mut::<Signer>::try_from_info(authority)?;
+        let program_data = ProgramData::<P>::try_from_info(program_data)?;
+        let registry = Mut::<SystemAccount>::try_from_info(registry)?;
+        let system = Program::try_from_info(system)?;
+
+        if program_data.upgrade_authority_address()? != Some(*authority.key()) {
+            return Err(Error::UpgradeAuthorityMismatch.into());
+        }
+
+        *accounts = rem;
+
+        Ok(InitRoles {
+            authority,
+            program_data,
+            registry,
+            system,
+        })
+    }
+}
+
+/// The owner of the registry of the program `P`.
+///
+/// Accounts:
+/// * the owner
+/// * the registry
+pub struct RegistryOwner<'a, P>
+where
+    P: ProgramId,
+{
+    pub owner: Signer<'a>,
+    pub registry: Mut<Singleton<'a, RoleRegistry<P>>>,
+}
+
+impl<'a, P> HandlerContext<'a> for RegistryOwner<'a, P>
+where
+    P: ProgramId,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [owner, registry, rem @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let owner = Signer::try_from_info(owner)?;
+        let registry = Mut::<Singleton<RoleRegistry<P>>>::try_from_info(registry)?;
+
+        if &registry.data()?.owner != owner.key() {
+            return Err(Error::RegistryOwnerMismatch.into());
+        }
+
+        *accounts = rem;
+
+        Ok(RegistryOwner { owner, registry })
+    }
+}
+
+/// The owner of the registry of the program `P` granting or revoking roles, the registry growing
+/// and shrinking with its members.
+///
+/// Accounts:
+/// * the owner, paying for the new members and refunded for the removed ones
+/// * the registry
+/// * the System program
+pub struct ManageRoles<'a, P>
+where
+    P: ProgramId,
+{
+    pub owner: Mut<Signer<'a>>,
+    pub registry: Mut<Singleton<'a, RoleRegistry<P>>>,
+    pub system: Program<'a, System>,
+}
+
+impl<'a, P> HandlerContext<'a> for ManageRoles<'a, P>
+where
+    P: ProgramId,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [owner, registry, system, rem @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let owner = Mut::<Signer>::try_from_info(owner)?;
+        let registry = Mut::<Singleton<RoleRegistry<P>>>::try_from_info(registry)?;
+        let system = Program::try_from_info(system)?;
+
+        if &registry.data()?.owner != owner.key() {
+            return Err(Error::RegistryOwnerMismatch.into());
+        }
+
+        *accounts = rem;
+
+        Ok(ManageRoles {
+            owner,
+            registry,
+            system,
+        })
+    }
+}
+
+/// The pending owner of the registry of the program `P`, accepting the ownership.
+///
+/// Accounts:
+/// * the pending owner
+/// * the registry
+pub struct PendingOwner<'a, P>
+where
+    P: ProgramId,
+{
+    pub pending_owner: Signer<'a>,
+    pub registry: Mut<Singleton<'a, RoleRegistry<P>>>,
+}
+
+impl<'a, P> HandlerContext<'a> for PendingOwner<'a, P>
+where
+    P: ProgramId,
+{
+    fn from_entrypoint(
+        accounts: &mut &'a [RawAccountInfo],
+        _instruction_data: &mut &'a [u8],
+    ) -> Result<Self, ProgramError> {
+        let [pending_owner, registry, rem @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let pending_owner = Signer::try_from_info(pending_owner)?;
+        let registry = Mut::<Singleton<RoleRegistry<P>>>::try_from_info(registry)?;
+
+        if registry.data()?.pending_owner.get() != Some(pending_owner.key()) {
+            return Err(Error::RegistryOwnerMismatch.into());
+        }
+
+        *accounts = rem;
+
+        Ok(PendingOwner {
+            pending_owner,
+            registry,
+        })
+    }
+}
@@ -0,0 +1,140 @@
+//! Handlers managing the registry of a program, to list in its `handlers!` with the marker
+//! declared by `program_id!`, like `typhoon::lib::grant_role::<MyProgram>`.
+
+use {
+    crate::{
+        Authority, InitRoles, ManageRoles, Pauser, PendingOwner, RegistryOwner, RoleMember,
+        RoleRegistry,
+    },
+    typhoon_accounts::{
+        FromAccountInfo, Mut, ProgramId, ReadableAccount, Singleton, SingletonData, Tail,
+        WritableAccount,
+    },
+    typhoon_context::Args,
+    typhoon_errors::Error,
+    typhoon_pod::PodPubkeyOption,
+    typhoon_program::{program_error::ProgramError, pubkey::Pubkey, signer},
+    typhoon_traits::{SystemCpi, TailAccount},
+};
+
+/// Creates the registry of the program `P` without members, owned by its upgrade authority.
+pub fn initialize_roles<P>(ctx: InitRoles<P>) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    let (address, bump) = RoleRegistry::<P>::find_address();
+    if ctx.registry.key() != &address {
+        return Err(Error::AccountAddressMismatch.into());
+    }
+
+    ctx.registry.create_account(
+        &ctx.authority,
+        &P::ID,
+        RoleRegistry::<P>::space(0) as u64,
+        Some(&[signer!(RoleRegistry::<P>::SEED, &[bump])]),
+    )?;
+
+    let registry = Mut::<Singleton<RoleRegistry<P>>>::try_from_info(ctx.registry.as_ref())?;
+    registry.mut_data()?.owner = *ctx.authority.key();
+
+    Ok(())
+}
+
+/// Grants the roles of `member` to its key, which becomes a member if it wasn't one. At least one
+/// role has to be granted, a member always has one.
+pub fn grant_role<P>(member: Args<RoleMember>, ctx: ManageRoles<P>) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    if u64::from(member.roles) == 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let position = ctx.registry.data()?.position(&member.key);
+
+    match position {
+        Some(index) => {
+            let mut registry = ctx.registry.mut_data()?;
+            let roles = &mut registry.members[index].roles;
+            *roles = (u64::from(*roles) | u64::from(member.roles)).into();
+        }
+        None => ctx.registry.push(&*member, &ctx.owner)?,
+    }
+
+    Ok(())
+}
+
+/// Revokes the roles of `member` from its key, which stops being a member once it has none left.
+pub fn revoke_role<P>(member: Args<RoleMember>, ctx: ManageRoles<P>) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    let index = ctx
+        .registry
+        .data()?
+        .position(&member.key)
+        .ok_or(Error::RoleNotGranted)?;
+
+    let remaining = {
+        let mut registry = ctx.registry.mut_data()?;
+        let roles = &mut registry.members[index].roles;
+        *roles = (u64::from(*roles) & !u64::from(member.roles)).into();
+        u64::from(*roles)
+    };
+
+    if remaining == 0 {
+        ctx.registry.swap_remove(index, &ctx.owner)?;
+    }
+
+    Ok(())
+}
+
+/// Proposes `new_owner` as the owner of the registry, who has to accept it with
+/// [`accept_ownership`]. Proposing another key replaces the pending one.
+pub fn transfer_ownership<P>(
+    new_owner: Args<Pubkey>,
+    ctx: RegistryOwner<P>,
+) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    ctx.registry.mut_data()?.pending_owner = PodPubkeyOption::some(*new_owner)?;
+
+    Ok(())
+}
+
+/// Makes the pending owner the owner of the registry.
+pub fn accept_ownership<P>(ctx: PendingOwner<P>) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    let mut registry = ctx.registry.mut_data()?;
+    registry.owner = *ctx.pending_owner.key();
+    registry.pending_owner = PodPubkeyOption::none();
+
+    Ok(())
+}
+
+/// Pauses the handlers with a `#[constraint(pausable)]` registry.
+pub fn pause<'a, P>(
+    ctx: Authority<'a, Pauser, P, Mut<Singleton<'a, RoleRegistry<P>>>>,
+) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    ctx.registry.mut_data()?.paused = true.into();
+
+    Ok(())
+}
+
+/// Resumes the handlers with a `#[constraint(pausable)]` registry.
+pub fn unpause<'a, P>(
+    ctx: Authority<'a, Pauser, P, Mut<Singleton<'a, RoleRegistry<P>>>>,
+) -> Result<(), ProgramError>
+where
+    P: ProgramId,
+{
+    ctx.registry.mut_data()?.paused = false.into();
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+//! Role-based access control shared by the programs: a registry of the roles granted by an owner,
+//! the contexts checking them and the instructions managing the registry.
+
+mod contexts;
+mod instructions;
+mod registry;
+mod role;
+
+pub use {contexts::*, instructions::*, registry::*, role::*};
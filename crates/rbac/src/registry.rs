@@ -0,0 +1,128 @@
+use {
+    crate::Role,
+    bytemuck::{Pod, Zeroable},
+    std::marker::PhantomData,
+    typhoon_accounts::{
+        read_tail, read_tail_mut, AccountData, Discriminator, Owner, ProgramId, ProgramOwned,
+        SingletonData, Tail,
+    },
+    typhoon_pod::{PodBool, PodPubkeyOption, PodU64},
+    typhoon_program::pubkey::Pubkey,
+};
+
+/// A key of the registry and the bits of the roles granted to it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+pub struct RoleMember {
+    pub key: Pubkey,
+    pub roles: PodU64,
+}
+
+/// The fields of a [`RoleRegistry`] preceding its members.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct RoleRegistryHeader {
+    pub owner: Pubkey,
+    pub pending_owner: PodPubkeyOption,
+    pub paused: PodBool,
+}
+
+/// The roles granted by the owner of the program `P`, stored at a single address like an
+/// `#[account(singleton)]` and growing with its members like a `#[tail]`.
+///
+/// The owner grants and revokes the roles, and hands the registry over in two steps: the new
+/// owner is pending until it accepts.
+#[repr(C)]
+pub struct RoleRegistry<P> {
+    pub owner: Pubkey,
+    pub pending_owner: PodPubkeyOption,
+    pub paused: PodBool,
+    _program: PhantomData<P>,
+    pub members: [RoleMember],
+}
+
+// Everything is aligned on 1 byte, so neither the header nor the members need padding.
+const _: () = assert!(
+    core::mem::align_of::<RoleRegistryHeader>() == 1 && core::mem::align_of::<RoleMember>() == 1
+);
+
+impl<P> RoleRegistry<P> {
+    /// Bits of the roles granted to `key`, `0` if it isn't a member.
+    pub fn roles(&self, key: &Pubkey) -> u64 {
+        self.position(key)
+            .map_or(0, |index| self.members[index].roles.into())
+    }
+
+    pub fn has_role<R: Role>(&self, key: &Pubkey) -> bool {
+        self.roles(key) & R::MASK == R::MASK
+    }
+
+    /// Index of `key` in the members.
+    pub fn position(&self, key: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| &member.key == key)
+    }
+}
+
+impl<P> Owner for RoleRegistry<P>
+where
+    P: ProgramId,
+{
+    const OWNER: Pubkey = P::ID;
+}
+
+// The registry is created by the handlers of `P`, run by the program itself
+impl<P> ProgramOwned for RoleRegistry<P> where P: ProgramId {}
+
+impl<P> Discriminator for RoleRegistry<P> {
+    const DISCRIMINATOR: &'static [u8] = &[];
+}
+
+impl<P> SingletonData for RoleRegistry<P>
+where
+    P: ProgramId,
+{
+    const SEED: &'static [u8] = b"RoleRegistry";
+}
+
+impl<P> AccountData for RoleRegistry<P> {
+    const MIN_LEN: usize = core::mem::size_of::<RoleRegistryHeader>();
+
+    fn read(data: &[u8]) -> Option<&Self> {
+        read_tail(data)
+    }
+
+    fn read_mut(data: &mut [u8]) -> Option<&mut Self> {
+        read_tail_mut(data)
+    }
+}
+
+// SAFETY: the registry is `#[repr(C)]`, the header fields and a zero-sized marker followed by the
+// members, all aligned on 1 byte.
+unsafe impl<P> Tail for RoleRegistry<P> {
+    type Header = RoleRegistryHeader;
+    type Entry = RoleMember;
+
+    fn tail(&self) -> &[Self::Entry] {
+        &self.members
+    }
+
+    fn tail_mut(&mut self) -> &mut [Self::Entry] {
+        &mut self.members
+    }
+
+    fn from_raw_parts(data: *const u8, len: usize) -> *const Self {
+        core::ptr::slice_from_raw_parts(data, len) as *const Self
+    }
+}
+
+/// Data telling whether the program is paused, checked by `#[constraint(pausable)]` before the
+/// handlers opting into it.
+pub trait Pausable {
+    fn is_paused(&self) -> bool;
+}
+
+impl<P> Pausable for RoleRegistry<P> {
+    fn is_paused(&self) -> bool {
+        self.paused.into()
+    }
+}
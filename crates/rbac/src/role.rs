@@ -0,0 +1,20 @@
+/// A role of the registry, granted as one or more bits of `MASK` to its members.
+///
+/// The roles below use the first bits, a program can declare its own ones with the others.
+pub trait Role {
+    const MASK: u64;
+}
+
+/// Runs the day-to-day operations of the program.
+pub struct Operator;
+
+impl Role for Operator {
+    const MASK: u64 = 1 << 0;
+}
+
+/// Pauses and unpauses the program.
+pub struct Pauser;
+
+impl Role for Pauser {
+    const MASK: u64 = 1 << 1;
+}
@@ -0,0 +1,51 @@
+use {
+    typhoon_accounts::{
+        AssociatedTokenProgram, Mut, ProgramId, ReadableAccount, SystemAccount, UncheckedAccount,
+        WritableAccount,
+    },
+    typhoon_program::{
+        cpi::{CpiAccount, CpiInstruction},
+        program_error::ProgramError,
+    },
+};
+
+const CREATE: u8 = 0;
+const CREATE_IDEMPOTENT: u8 = 1;
+
+pub trait AssociatedTokenCpi: WritableAccount {
+    /// Creates this account as the associated token account of `wallet` for `mint`.
+    ///
+    /// With `idempotent`, the instruction succeeds if the account already exists.
+    fn create_associated_token_account(
+        &self,
+        payer: &impl ReadableAccount,
+        wallet: &impl ReadableAccount,
+        mint: &impl ReadableAccount,
+        system_program: &impl ReadableAccount,
+        token_program: &impl ReadableAccount,
+        idempotent: bool,
+    ) -> Result<(), ProgramError> {
+        let data = if idempotent {
+            [CREATE_IDEMPOTENT]
+        } else {
+            [CREATE]
+        };
+
+        CpiInstruction {
+            program_id: &AssociatedTokenProgram::ID,
+            accounts: [
+                CpiAccount::writable_signer(payer),
+                CpiAccount::writable(self),
+                CpiAccount::readonly(wallet),
+                CpiAccount::readonly(mint),
+                CpiAccount::readonly(system_program),
+                CpiAccount::readonly(token_program),
+            ],
+            data: &data,
+        }
+        .invoke()
+    }
+}
+
+impl AssociatedTokenCpi for Mut<SystemAccount<'_>> {}
+impl AssociatedTokenCpi for Mut<UncheckedAccount<'_>> {}
@@ -0,0 +1,124 @@
+use {
+    bytemuck::{bytes_of, Pod},
+    typhoon_accounts::ReadableAccount,
+    typhoon_errors::Error,
+    typhoon_program::{
+        cpi::{CpiAccount, CpiInstruction},
+        log::sol_log_data,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        signer, RawAccountInfo,
+    },
+};
+
+/// Seed of the PDA signing the self CPI used by [`Event::emit_cpi`].
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+/// Tag prefixing the self CPI instruction data, compatible with Anchor's `emit_cpi!`.
+pub const EVENT_IX_TAG_LE: [u8; 8] = 0x1d9a_cb51_2ea5_45e4_u64.to_le_bytes();
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct EventCpiData<T> {
+    tag: [u8; 8],
+    discriminator: [u8; 8],
+    event: T,
+}
+
+pub trait Event: Pod {
+    const DISCRIMINATOR: [u8; 8];
+
+    /// Logs the event with `sol_log_data`.
+    fn emit(&self) {
+        sol_log_data(&[&Self::DISCRIMINATOR, bytes_of(self)]);
+    }
+
+    /// Emits the event through a CPI to the current program so it can't be truncated from the
+    /// logs. The program account needs to be part of the instruction accounts.
+    ///
+    /// `authority` is the event authority PDA of the program and its bump, the `EVENT_AUTHORITY`
+    /// constant declared by `program_id!`.
+    fn emit_cpi(
+        &self,
+        program_id: &Pubkey,
+        authority: &(Pubkey, u8),
+        event_authority: &impl ReadableAccount,
+    ) -> Result<(), ProgramError> {
+        let (authority, bump) = authority;
+        if event_authority.key() != authority {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        let data = EventCpiData {
+            tag: EVENT_IX_TAG_LE,
+            discriminator: Self::DISCRIMINATOR,
+            event: *self,
+        };
+        // SAFETY: the header is 16 bytes long so there is no padding before a `Pod` event.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(
+                &data as *const EventCpiData<Self> as *const u8,
+                std::mem::size_of::<EventCpiData<Self>>(),
+            )
+        };
+
+        CpiInstruction {
+            program_id,
+            accounts: [CpiAccount::readonly_signer(event_authority)],
+            data: bytes,
+        }
+        .invoke_signed(&[signer!(EVENT_AUTHORITY_SEED, &[*bump])])
+    }
+}
+
+/// Handles the self CPI made by [`Event::emit_cpi`], checking that it was signed by
+/// `event_authority`, the PDA declared by `program_id!`.
+pub fn handle_event_cpi(
+    event_authority: &Pubkey,
+    accounts: &[RawAccountInfo],
+) -> Result<(), ProgramError> {
+    let [authority, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !authority.is_signer() {
+        return Err(Error::AccountNotSigner.into());
+    }
+
+    if authority.key() != event_authority {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(())
+}
+
+/// Emits an event with `sol_log_data`.
+///
+/// ```ignore
+/// emit!(Deposited { amount });
+/// ```
+#[macro_export]
+macro_rules! emit {
+    ($event:expr) => {
+        $crate::Event::emit(&$event)
+    };
+}
+
+/// Emits an event through a self CPI signed by the event authority PDA, using the `ID` and
+/// `EVENT_AUTHORITY` constants declared by `program_id!`.
+///
+/// ```ignore
+/// emit_cpi!(Deposited { amount }, &ctx.event_authority)?;
+/// ```
+#[macro_export]
+#[allow(clippy::crate_in_macro_def)]
+macro_rules! emit_cpi {
+    ($event:expr, $event_authority:expr) => {
+        $crate::Event::emit_cpi(
+            &$event,
+            &crate::ID,
+            &crate::EVENT_AUTHORITY,
+            $event_authority,
+        )
+    };
+}
@@ -0,0 +1,40 @@
+use typhoon_program::program_error::ProgramError;
+
+const MAX_SEED_LEN: usize = 32;
+
+/// Serialization of an instruction data without allocation.
+pub(crate) struct InstructionData<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> InstructionData<N> {
+    pub(crate) fn new(discriminator: &[u8]) -> Self {
+        InstructionData {
+            data: [0; N],
+            len: 0,
+        }
+        .with(discriminator)
+    }
+
+    pub(crate) fn with(mut self, bytes: &[u8]) -> Self {
+        self.data[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        self
+    }
+
+    /// Writes a bincode `String` seed.
+    pub(crate) fn with_seed(self, seed: &str) -> Result<Self, ProgramError> {
+        if seed.len() > MAX_SEED_LEN {
+            return Err(ProgramError::MaxSeedLengthExceeded);
+        }
+
+        Ok(self
+            .with(&(seed.len() as u64).to_le_bytes())
+            .with(seed.as_bytes()))
+    }
+
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+}
@@ -1,9 +1,17 @@
 use {
-    typhoon_accounts::{Mut, Signer, SignerAccount, WritableAccount},
-    typhoon_program::program_error::ProgramError,
+    typhoon_accounts::{
+        Account, AccountData, Multisig, Mut, ProgramOwned, Signer, Singleton, SingletonData,
+        WritableAccount,
+    },
+    typhoon_program::{program_error::ProgramError, sysvars::rent::Rent},
 };
 
-pub trait Lamports: WritableAccount + SignerAccount {
+/// Moves lamports directly between accounts without the System program.
+///
+/// Only the owner program of an account is allowed to debit it, so this is implemented for the
+/// accounts of the current program, `Mut<Account<T>>` and `Mut<Singleton<T>>` where `T` is
+/// `ProgramOwned`.
+pub trait Lamports: WritableAccount {
     fn send(&self, to: &impl WritableAccount, amount: u64) -> Result<(), ProgramError> {
         let mut payer_lamports = self.mut_lamports()?;
         let mut recipient_lamports = to.mut_lamports()?;
@@ -20,18 +28,40 @@ pub trait Lamports: WritableAccount + SignerAccount {
 
     fn send_all(&self, to: &impl WritableAccount) -> Result<(), ProgramError> {
         let amount = *self.lamports()?;
-        let mut payer_lamports = self.mut_lamports()?;
-        let mut recipient_lamports = to.mut_lamports()?;
 
-        *payer_lamports = payer_lamports
+        self.send(to, amount)
+    }
+
+    /// Sends everything above the rent-exempt minimum of the account, returning the sent amount.
+    fn send_excess(&self, to: &impl WritableAccount) -> Result<u64, ProgramError> {
+        let minimum_balance = Rent::get()?.minimum_balance(self.as_ref().data_len());
+        let amount = self.lamports()?.saturating_sub(minimum_balance);
+
+        self.send(to, amount)?;
+
+        Ok(amount)
+    }
+
+    /// Sends `amount` only if the account stays rent-exempt afterwards.
+    fn send_rent_exempt(&self, to: &impl WritableAccount, amount: u64) -> Result<(), ProgramError> {
+        let minimum_balance = Rent::get()?.minimum_balance(self.as_ref().data_len());
+        let remaining = self
+            .lamports()?
             .checked_sub(amount)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
-        *recipient_lamports = recipient_lamports
-            .checked_add(amount)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
+            .ok_or(ProgramError::InsufficientFunds)?;
 
-        Ok(())
+        if remaining < minimum_balance {
+            return Err(ProgramError::InsufficientFunds);
+        }
+
+        self.send(to, amount)
     }
 }
 
 impl Lamports for Mut<Signer<'_>> {}
+
+impl<T> Lamports for Mut<Account<'_, T>> where T: ProgramOwned + AccountData + ?Sized {}
+
+impl<T> Lamports for Mut<Singleton<'_, T>> where T: ProgramOwned + SingletonData + ?Sized {}
+
+impl<T> Lamports for Multisig<'_, T> where T: Lamports {}
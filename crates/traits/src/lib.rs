@@ -1,4 +1,10 @@
+mod associated_token;
+mod event;
+mod instruction_data;
 mod lamport;
+mod migrate;
 mod system;
+mod tail;
+mod token;
 
-pub use {lamport::*, system::*};
+pub use {associated_token::*, event::*, lamport::*, migrate::*, system::*, tail::*, token::*};
@@ -0,0 +1,73 @@
+use {
+    crate::SystemCpi,
+    bytemuck::{bytes_of, Pod},
+    typhoon_accounts::{
+        FromAccountInfo, Mut, Owner, ReadableAccount, UncheckedAccount, Versioned, WritableAccount,
+    },
+    typhoon_program::{program_error::ProgramError, sysvars::rent::Rent, RawAccountInfo},
+};
+
+/// Conversion of an account written with the older layout `From`, applied in place by
+/// `#[constraint(migrate = From, payer = ...)]` before the account is loaded.
+///
+/// ```ignore
+/// #[account(discriminator = b"pool", version = 1)]
+/// pub struct PoolV1 {
+///     pub reserve: u64,
+/// }
+///
+/// #[account(discriminator = b"pool", version = 2)]
+/// pub struct Pool {
+///     pub reserve: u64,
+///     pub fee: u64,
+/// }
+///
+/// impl Migrate<PoolV1> for Pool {
+///     fn migrate(from: &PoolV1) -> Self {
+///         Pool { reserve: from.reserve, fee: 0 }
+///     }
+/// }
+/// ```
+pub trait Migrate<From>: Owner + Versioned + Pod
+where
+    From: Versioned + Pod,
+{
+    fn migrate(from: &From) -> Self;
+
+    /// Converts the data of `info` when it holds `From`, returning whether it did. The account is
+    /// grown to the new layout with the missing rent paid by `payer`, but never shrunk.
+    fn upgrade(info: &RawAccountInfo, payer: &impl SystemCpi) -> Result<bool, ProgramError> {
+        let from = {
+            let data = info.try_borrow_data()?;
+            if info.owner() != &Self::OWNER || !data.starts_with(From::DISCRIMINATOR) {
+                return Ok(false);
+            }
+
+            let bytes = data
+                .get(From::DISCRIMINATOR.len()..)
+                .and_then(|data| data.get(..core::mem::size_of::<From>()))
+                .ok_or(ProgramError::AccountDataTooSmall)?;
+
+            bytemuck::pod_read_unaligned::<From>(bytes)
+        };
+
+        let account = Mut::<UncheckedAccount>::try_from_info(info)?;
+        let len = Self::DISCRIMINATOR.len() + core::mem::size_of::<Self>();
+        let new_len = len.max(info.data_len());
+        let minimum_balance = Rent::get()?.minimum_balance(new_len);
+        let lamports = *account.lamports()?;
+
+        if lamports < minimum_balance {
+            payer.transfer(&account, minimum_balance - lamports, None)?;
+        }
+
+        account.realloc(new_len, true)?;
+
+        let mut data = account.mut_data()?;
+        data.fill(0);
+        data[..Self::DISCRIMINATOR.len()].copy_from_slice(Self::DISCRIMINATOR);
+        data[Self::DISCRIMINATOR.len()..len].copy_from_slice(bytes_of(&Self::migrate(&from)));
+
+        Ok(true)
+    }
+}
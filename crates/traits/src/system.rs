@@ -1,31 +1,100 @@
 use {
+    crate::instruction_data::InstructionData,
     typhoon_accounts::{
-        Mut, ReadableAccount, Signer as SignerAccount, SystemAccount, WritableAccount,
+        Mut, ReadableAccount, Signer as SignerAccount, SystemAccount, UncheckedAccount,
+        WritableAccount,
     },
     typhoon_program::{
+        cpi::{CpiAccount, CpiInstruction},
         program_error::ProgramError,
         pubkey::Pubkey,
-        system_program::instructions::{Allocate, Assign, CreateAccount, Transfer},
-        sysvars::{rent::Rent, Sysvar},
+        system_program,
+        sysvars::rent::Rent,
         SignerSeeds,
     },
 };
 
+const CREATE_ACCOUNT: u32 = 0;
+const ASSIGN: u32 = 1;
+const TRANSFER: u32 = 2;
+const CREATE_ACCOUNT_WITH_SEED: u32 = 3;
+const ADVANCE_NONCE_ACCOUNT: u32 = 4;
+const AUTHORIZE_NONCE_ACCOUNT: u32 = 7;
+const ALLOCATE: u32 = 8;
+const ALLOCATE_WITH_SEED: u32 = 9;
+const ASSIGN_WITH_SEED: u32 = 10;
+const TRANSFER_WITH_SEED: u32 = 11;
+
 pub trait SystemCpi: WritableAccount {
-    fn allocate(&self, new_space: u64) -> Result<(), ProgramError> {
-        Allocate {
-            account: self.as_ref(),
-            space: new_space,
+    fn allocate(&self, new_space: u64, seeds: Option<&[SignerSeeds]>) -> Result<(), ProgramError> {
+        let data =
+            InstructionData::<12>::new(&ALLOCATE.to_le_bytes()).with(&new_space.to_le_bytes());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [CpiAccount::writable_signer(self)],
+            data: data.as_slice(),
         }
-        .invoke()
+        .invoke_signed(seeds.unwrap_or_default())
     }
 
-    fn assign(&self, owner: &Pubkey) -> Result<(), ProgramError> {
-        Assign {
-            account: self.as_ref(),
-            owner,
+    fn allocate_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        seed: &str,
+        space: u64,
+        owner: &Pubkey,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<116>::new(&ALLOCATE_WITH_SEED.to_le_bytes())
+            .with(base.key().as_ref())
+            .with_seed(seed)?
+            .with(&space.to_le_bytes())
+            .with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(base),
+            ],
+            data: data.as_slice(),
         }
-        .invoke()
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn assign(&self, owner: &Pubkey, seeds: Option<&[SignerSeeds]>) -> Result<(), ProgramError> {
+        let data = InstructionData::<36>::new(&ASSIGN.to_le_bytes()).with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [CpiAccount::writable_signer(self)],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn assign_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        seed: &str,
+        owner: &Pubkey,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<108>::new(&ASSIGN_WITH_SEED.to_le_bytes())
+            .with(base.key().as_ref())
+            .with_seed(seed)?
+            .with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(base),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
     }
 
     fn create_account(
@@ -35,25 +104,137 @@ pub trait SystemCpi: WritableAccount {
         space: u64,
         seeds: Option<&[SignerSeeds]>,
     ) -> Result<(), ProgramError> {
-        CreateAccount {
-            from: payer.as_ref(),
-            lamports: Rent::get()?.minimum_balance(space as usize),
-            owner,
-            space,
-            to: self.as_ref(),
+        let lamports = Rent::get()?.minimum_balance(space as usize);
+        let data = InstructionData::<52>::new(&CREATE_ACCOUNT.to_le_bytes())
+            .with(&lamports.to_le_bytes())
+            .with(&space.to_le_bytes())
+            .with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable_signer(payer),
+                CpiAccount::writable_signer(self),
+            ],
+            data: data.as_slice(),
         }
         .invoke_signed(seeds.unwrap_or_default())
     }
 
-    fn transfer(&self, to: &impl WritableAccount, amount: u64) -> Result<(), ProgramError> {
-        Transfer {
-            from: self.as_ref(),
-            lamports: amount,
-            to: to.as_ref(),
+    fn create_account_with_seed(
+        &self,
+        payer: &impl ReadableAccount,
+        base: &impl ReadableAccount,
+        seed: &str,
+        owner: &Pubkey,
+        space: u64,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let lamports = Rent::get()?.minimum_balance(space as usize);
+        let data = InstructionData::<124>::new(&CREATE_ACCOUNT_WITH_SEED.to_le_bytes())
+            .with(base.key().as_ref())
+            .with_seed(seed)?
+            .with(&lamports.to_le_bytes())
+            .with(&space.to_le_bytes())
+            .with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable_signer(payer),
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(base),
+            ],
+            data: data.as_slice(),
         }
-        .invoke()
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn transfer(
+        &self,
+        to: &impl WritableAccount,
+        amount: u64,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<12>::new(&TRANSFER.to_le_bytes()).with(&amount.to_le_bytes());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [CpiAccount::writable_signer(self), CpiAccount::writable(to)],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn transfer_with_seed(
+        &self,
+        base: &impl ReadableAccount,
+        to: &impl WritableAccount,
+        amount: u64,
+        from_seed: &str,
+        from_owner: &Pubkey,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<84>::new(&TRANSFER_WITH_SEED.to_le_bytes())
+            .with(&amount.to_le_bytes())
+            .with_seed(from_seed)?
+            .with(from_owner.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(base),
+                CpiAccount::writable(to),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    /// Advances the nonce stored in this account, `recent_blockhashes` being the
+    /// `RecentBlockhashes` sysvar.
+    fn advance_nonce(
+        &self,
+        recent_blockhashes: &impl ReadableAccount,
+        authority: &impl ReadableAccount,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<4>::new(&ADVANCE_NONCE_ACCOUNT.to_le_bytes());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly(recent_blockhashes),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn authorize_nonce(
+        &self,
+        authority: &impl ReadableAccount,
+        new_authority: &Pubkey,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<36>::new(&AUTHORIZE_NONCE_ACCOUNT.to_le_bytes())
+            .with(new_authority.as_ref());
+
+        CpiInstruction {
+            program_id: &system_program::ID,
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
     }
 }
 
 impl SystemCpi for Mut<SystemAccount<'_>> {}
 impl SystemCpi for Mut<SignerAccount<'_>> {}
+impl SystemCpi for Mut<UncheckedAccount<'_>> {}
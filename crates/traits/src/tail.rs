@@ -0,0 +1,97 @@
+use {
+    crate::{Lamports, SystemCpi},
+    typhoon_accounts::{
+        Account, Mut, ProgramOwned, Singleton, SingletonData, Tail, WritableAccount,
+    },
+    typhoon_program::{program_error::ProgramError, sysvars::rent::Rent},
+};
+
+/// Grows and shrinks the `#[tail]` entries of an account, keeping it rent-exempt.
+pub trait TailAccount<T>: Sized + Lamports + WritableAccount<DataType = T>
+where
+    T: Tail + ?Sized,
+{
+    /// Appends `entry`, the missing rent being paid by `payer`.
+    fn push(&self, entry: &T::Entry, payer: &impl SystemCpi) -> Result<(), ProgramError> {
+        let new_len = self.as_ref().data_len() + core::mem::size_of::<T::Entry>();
+        let minimum_balance = Rent::get()?.minimum_balance(new_len);
+        let lamports = *self.lamports()?;
+
+        if lamports < minimum_balance {
+            payer.transfer(self, minimum_balance - lamports, None)?;
+        }
+
+        self.realloc(new_len, false)?;
+
+        let mut data = self.mut_data()?;
+        let last = data
+            .tail_mut()
+            .last_mut()
+            .ok_or(ProgramError::InvalidAccountData)?;
+        *last = *entry;
+
+        Ok(())
+    }
+
+    /// Removes the entry at `index` by shifting the following ones, the rent in excess being sent to
+    /// `recipient`.
+    fn remove(
+        &self,
+        index: usize,
+        recipient: &impl WritableAccount,
+    ) -> Result<T::Entry, ProgramError> {
+        let entry = {
+            let mut data = self.mut_data()?;
+            let tail = data.tail_mut();
+            let entry = *tail.get(index).ok_or(ProgramError::InvalidArgument)?;
+            tail.copy_within(index + 1.., index);
+            entry
+        };
+
+        shrink(self, recipient)?;
+
+        Ok(entry)
+    }
+
+    /// Removes the entry at `index` by moving the last one in its place, the rent in excess being
+    /// sent to `recipient`.
+    fn swap_remove(
+        &self,
+        index: usize,
+        recipient: &impl WritableAccount,
+    ) -> Result<T::Entry, ProgramError> {
+        let entry = {
+            let mut data = self.mut_data()?;
+            let tail = data.tail_mut();
+            let entry = *tail.get(index).ok_or(ProgramError::InvalidArgument)?;
+            let last = tail.len() - 1;
+            tail.swap(index, last);
+            entry
+        };
+
+        shrink(self, recipient)?;
+
+        Ok(entry)
+    }
+}
+
+impl<T> TailAccount<T> for Mut<Account<'_, T>> where T: ProgramOwned + Tail + ?Sized {}
+
+impl<T> TailAccount<T> for Mut<Singleton<'_, T>> where
+    T: ProgramOwned + SingletonData + Tail + ?Sized
+{
+}
+
+/// Drops the last entry and sends the rent it was holding to `recipient`.
+fn shrink<T, A>(account: &A, recipient: &impl WritableAccount) -> Result<(), ProgramError>
+where
+    T: Tail + ?Sized,
+    A: TailAccount<T>,
+{
+    let new_len = account.as_ref().data_len() - core::mem::size_of::<T::Entry>();
+
+    account.realloc(new_len, false)?;
+    account.send_excess(recipient)?;
+
+    Ok(())
+}
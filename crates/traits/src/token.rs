@@ -0,0 +1,237 @@
+use {
+    crate::instruction_data::InstructionData,
+    typhoon_accounts::{
+        InterfaceAccount, Mint, MintData, Mut, ReadableAccount, TokenAccount, TokenAccountData,
+        UncheckedAccount, WritableAccount,
+    },
+    typhoon_program::{
+        cpi::{CpiAccount, CpiInstruction},
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        SignerSeeds,
+    },
+};
+
+const APPROVE: u8 = 4;
+const REVOKE: u8 = 5;
+const SET_AUTHORITY: u8 = 6;
+const MINT_TO: u8 = 7;
+const BURN: u8 = 8;
+const CLOSE_ACCOUNT: u8 = 9;
+const TRANSFER_CHECKED: u8 = 12;
+const INITIALIZE_ACCOUNT_3: u8 = 18;
+const INITIALIZE_MINT_2: u8 = 20;
+
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuthorityType {
+    MintTokens,
+    FreezeAccount,
+    AccountOwner,
+    CloseAccount,
+}
+
+/// CPIs to the Token program, `self` being the first account of the instruction.
+///
+/// The instruction is sent to the owner of `self`, the owner check of the account ensuring it is
+/// the Token program, or the Token-2022 program for an `InterfaceAccount`.
+pub trait TokenCpi: WritableAccount {
+    /// Initializes this mint, which must already be allocated and owned by the Token program.
+    fn initialize_mint(
+        &self,
+        decimals: u8,
+        mint_authority: &Pubkey,
+        freeze_authority: Option<&Pubkey>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<67>::new(&[INITIALIZE_MINT_2])
+            .with(&[decimals])
+            .with(mint_authority.as_ref());
+        let data = match freeze_authority {
+            Some(freeze_authority) => data.with(&[1]).with(freeze_authority.as_ref()),
+            None => data.with(&[0]),
+        };
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [CpiAccount::writable(self)],
+            data: data.as_slice(),
+        }
+        .invoke()
+    }
+
+    /// Initializes this token account, which must already be allocated and owned by the Token
+    /// program.
+    fn initialize_account(
+        &self,
+        mint: &impl ReadableAccount,
+        owner: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<33>::new(&[INITIALIZE_ACCOUNT_3]).with(owner.as_ref());
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [CpiAccount::writable(self), CpiAccount::readonly(mint)],
+            data: data.as_slice(),
+        }
+        .invoke()
+    }
+
+    fn transfer_checked(
+        &self,
+        mint: &impl ReadableAccount,
+        to: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        amount: u64,
+        decimals: u8,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<10>::new(&[TRANSFER_CHECKED])
+            .with(&amount.to_le_bytes())
+            .with(&[decimals]);
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly(mint),
+                CpiAccount::writable(to),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    /// Mints `amount` tokens of this mint to `to`.
+    fn mint_to(
+        &self,
+        to: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        amount: u64,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<9>::new(&[MINT_TO]).with(&amount.to_le_bytes());
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::writable(to),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn burn(
+        &self,
+        mint: &impl WritableAccount,
+        authority: &impl ReadableAccount,
+        amount: u64,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<9>::new(&[BURN]).with(&amount.to_le_bytes());
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::writable(mint),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn approve(
+        &self,
+        delegate: &impl ReadableAccount,
+        owner: &impl ReadableAccount,
+        amount: u64,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<9>::new(&[APPROVE]).with(&amount.to_le_bytes());
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly(delegate),
+                CpiAccount::readonly_signer(owner),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn revoke(
+        &self,
+        owner: &impl ReadableAccount,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<1>::new(&[REVOKE]);
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(owner),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    fn close_account(
+        &self,
+        destination: &impl WritableAccount,
+        owner: &impl ReadableAccount,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<1>::new(&[CLOSE_ACCOUNT]);
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::writable(destination),
+                CpiAccount::readonly_signer(owner),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+
+    /// Sets or removes, when `new_authority` is `None`, an authority of this mint or account.
+    fn set_authority(
+        &self,
+        authority: &impl ReadableAccount,
+        authority_type: AuthorityType,
+        new_authority: Option<&Pubkey>,
+        seeds: Option<&[SignerSeeds]>,
+    ) -> Result<(), ProgramError> {
+        let data = InstructionData::<35>::new(&[SET_AUTHORITY]).with(&[authority_type as u8]);
+        let data = match new_authority {
+            Some(new_authority) => data.with(&[1]).with(new_authority.as_ref()),
+            None => data.with(&[0]),
+        };
+
+        CpiInstruction {
+            program_id: self.owner(),
+            accounts: [
+                CpiAccount::writable(self),
+                CpiAccount::readonly_signer(authority),
+            ],
+            data: data.as_slice(),
+        }
+        .invoke_signed(seeds.unwrap_or_default())
+    }
+}
+
+impl TokenCpi for Mut<TokenAccount<'_>> {}
+impl TokenCpi for Mut<Mint<'_>> {}
+impl TokenCpi for Mut<InterfaceAccount<'_, TokenAccountData>> {}
+impl TokenCpi for Mut<InterfaceAccount<'_, MintData>> {}
+impl TokenCpi for Mut<UncheckedAccount<'_>> {}
@@ -0,0 +1,58 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::prelude::*,
+};
+
+program_id!("C5vsgAhGKVXRR6zkJJsXaSs3q8ue1ah1vaBCk8uZg2wY");
+
+#[context]
+pub struct InitCounterContext {
+    pub payer: Mut<Signer>,
+    #[constraint(upgrade_authority = payer)]
+    pub program_data: ProgramData<AccessControlProgram>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = core::mem::size_of::<Counter>()
+    )]
+    pub counter: Mut<Singleton<Counter>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct IncrementContext {
+    #[constraint(pausable)]
+    pub registry: Singleton<RoleRegistry<AccessControlProgram>>,
+    pub counter: Mut<Singleton<Counter>>,
+}
+
+handlers! {
+    initialize_roles::<AccessControlProgram>,
+    grant_role::<AccessControlProgram>,
+    revoke_role::<AccessControlProgram>,
+    transfer_ownership::<AccessControlProgram>,
+    accept_ownership::<AccessControlProgram>,
+    pause::<AccessControlProgram>,
+    unpause::<AccessControlProgram>,
+    initialize_counter,
+    increment,
+}
+
+/// Creates the counter, only allowed to the upgrade authority of the program.
+pub fn initialize_counter(_ctx: InitCounterContext) -> Result<(), ProgramError> {
+    Ok(())
+}
+
+pub fn increment(
+    _operator: Authority<Operator, AccessControlProgram>,
+    ctx: IncrementContext,
+) -> Result<(), ProgramError> {
+    ctx.counter.mut_data()?.count += 1;
+
+    Ok(())
+}
+
+#[account(singleton)]
+pub struct Counter {
+    pub count: u64,
+}
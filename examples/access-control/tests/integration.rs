@@ -0,0 +1,266 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        account::Account,
+        bpf_loader_upgradeable,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+const OPERATOR: u64 = 1 << 0;
+const PAUSER: u64 = 1 << 1;
+
+const HEADER_LEN: usize = 65;
+const MEMBER_LEN: usize = 40;
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/access_control.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn program_data(authority: Pubkey) -> Vec<u8> {
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+
+    data
+}
+
+struct Ctx {
+    svm: LiteSVM,
+    program_id: Pubkey,
+    registry: Pubkey,
+    counter: Pubkey,
+}
+
+impl Ctx {
+    fn send(&mut self, ix: Instruction, signer: &Keypair) -> bool {
+        self.svm.expire_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+
+        self.svm.send_transaction(tx).is_ok()
+    }
+
+    fn ix(&self, discriminator: u8, accounts: Vec<AccountMeta>, args: &[u8]) -> Instruction {
+        let mut data = vec![discriminator];
+        data.extend_from_slice(args);
+
+        Instruction {
+            program_id: self.program_id,
+            accounts,
+            data,
+        }
+    }
+
+    fn initialize_roles(&mut self, authority: &Keypair) -> bool {
+        let program_data = bpf_loader_upgradeable::get_program_data_address(&self.program_id);
+        let ix = self.ix(
+            0,
+            vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new(self.registry, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            &[],
+        );
+
+        self.send(ix, authority)
+    }
+
+    fn manage_role(&mut self, discriminator: u8, owner: &Keypair, key: Pubkey, roles: u64) -> bool {
+        let mut args = key.to_bytes().to_vec();
+        args.extend_from_slice(&roles.to_le_bytes());
+        let ix = self.ix(
+            discriminator,
+            vec![
+                AccountMeta::new(owner.pubkey(), true),
+                AccountMeta::new(self.registry, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            &args,
+        );
+
+        self.send(ix, owner)
+    }
+
+    fn transfer_ownership(&mut self, owner: &Keypair, new_owner: Pubkey) -> bool {
+        let ix = self.ix(
+            3,
+            vec![
+                AccountMeta::new_readonly(owner.pubkey(), true),
+                AccountMeta::new(self.registry, false),
+            ],
+            new_owner.as_ref(),
+        );
+
+        self.send(ix, owner)
+    }
+
+    fn registry_signer_ix(&mut self, discriminator: u8, signer: &Keypair) -> bool {
+        let ix = self.ix(
+            discriminator,
+            vec![
+                AccountMeta::new_readonly(signer.pubkey(), true),
+                AccountMeta::new(self.registry, false),
+            ],
+            &[],
+        );
+
+        self.send(ix, signer)
+    }
+
+    /// Creates the counter with `authority`, signing when it is the fee payer.
+    fn initialize_counter(&mut self, authority: Pubkey, payer: &Keypair) -> bool {
+        let program_data = bpf_loader_upgradeable::get_program_data_address(&self.program_id);
+        let ix = self.ix(
+            7,
+            vec![
+                AccountMeta::new(authority, authority == payer.pubkey()),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new(self.counter, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            &[],
+        );
+
+        self.send(ix, payer)
+    }
+
+    fn increment(&mut self, operator: &Keypair) -> bool {
+        let ix = self.ix(
+            8,
+            vec![
+                AccountMeta::new_readonly(operator.pubkey(), true),
+                AccountMeta::new_readonly(self.registry, false),
+                AccountMeta::new_readonly(self.registry, false),
+                AccountMeta::new(self.counter, false),
+            ],
+            &[],
+        );
+
+        self.send(ix, operator)
+    }
+
+    fn registry_data(&self) -> Vec<u8> {
+        self.svm.get_account(&self.registry).unwrap().data
+    }
+
+    fn count(&self) -> u64 {
+        let data = self.svm.get_account(&self.counter).unwrap().data;
+
+        u64::from_le_bytes(data[..8].try_into().unwrap())
+    }
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let operator_kp = Keypair::new();
+    let pauser_kp = Keypair::new();
+    let new_owner_kp = Keypair::new();
+
+    for kp in [&admin_kp, &operator_kp, &pauser_kp, &new_owner_kp] {
+        svm.airdrop(&kp.pubkey(), 10 * LAMPORTS_PER_SOL).unwrap();
+    }
+
+    let program_id = pubkey!("C5vsgAhGKVXRR6zkJJsXaSs3q8ue1ah1vaBCk8uZg2wY");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // The upgrade authority of the program is the admin
+    svm.set_account(
+        bpf_loader_upgradeable::get_program_data_address(&program_id),
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: program_data(admin_kp.pubkey()),
+            owner: bpf_loader_upgradeable::ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let mut ctx = Ctx {
+        svm,
+        program_id,
+        registry: Pubkey::find_program_address(&[b"RoleRegistry"], &program_id).0,
+        counter: Pubkey::find_program_address(&[b"Counter"], &program_id).0,
+    };
+
+    // Only the upgrade authority can create the registry
+    assert!(!ctx.initialize_roles(&operator_kp));
+    assert!(ctx.initialize_roles(&admin_kp));
+
+    let data = ctx.registry_data();
+    assert_eq!(data.len(), HEADER_LEN);
+    assert_eq!(&data[..32], admin_kp.pubkey().as_ref());
+
+    // Only the upgrade authority can create the counter, and it has to sign
+    assert!(!ctx.initialize_counter(operator_kp.pubkey(), &operator_kp));
+    assert!(!ctx.initialize_counter(admin_kp.pubkey(), &operator_kp));
+    assert!(ctx.initialize_counter(admin_kp.pubkey(), &admin_kp));
+
+    // Incrementing needs the operator role
+    assert!(!ctx.increment(&operator_kp));
+    assert!(!ctx.manage_role(1, &admin_kp, operator_kp.pubkey(), 0));
+    assert_eq!(ctx.registry_data().len(), HEADER_LEN);
+    assert!(!ctx.manage_role(1, &operator_kp, operator_kp.pubkey(), OPERATOR));
+    assert!(ctx.manage_role(1, &admin_kp, operator_kp.pubkey(), OPERATOR));
+    assert_eq!(ctx.registry_data().len(), HEADER_LEN + MEMBER_LEN);
+
+    assert!(ctx.increment(&operator_kp));
+    assert_eq!(ctx.count(), 1);
+
+    // Pausing needs the pauser role and stops the pausable handlers
+    assert!(!ctx.registry_signer_ix(5, &operator_kp));
+    assert!(ctx.manage_role(1, &admin_kp, pauser_kp.pubkey(), PAUSER));
+    assert!(ctx.registry_signer_ix(5, &pauser_kp));
+    assert!(!ctx.increment(&operator_kp));
+
+    assert!(ctx.registry_signer_ix(6, &pauser_kp));
+    assert!(ctx.increment(&operator_kp));
+    assert_eq!(ctx.count(), 2);
+
+    // Revoking the last role of a member removes it
+    assert!(ctx.manage_role(2, &admin_kp, operator_kp.pubkey(), OPERATOR));
+    let data = ctx.registry_data();
+    assert_eq!(data.len(), HEADER_LEN + MEMBER_LEN);
+    assert_eq!(
+        &data[HEADER_LEN..HEADER_LEN + 32],
+        pauser_kp.pubkey().as_ref()
+    );
+    assert!(!ctx.increment(&operator_kp));
+
+    // The ownership is transferred once accepted by the new owner
+    assert!(ctx.transfer_ownership(&admin_kp, new_owner_kp.pubkey()));
+    assert!(!ctx.registry_signer_ix(4, &operator_kp));
+    assert!(!ctx.manage_role(1, &new_owner_kp, operator_kp.pubkey(), OPERATOR));
+
+    assert!(ctx.registry_signer_ix(4, &new_owner_kp));
+    let data = ctx.registry_data();
+    assert_eq!(&data[..32], new_owner_kp.pubkey().as_ref());
+    assert_eq!(&data[32..64], &[0; 32]);
+
+    assert!(!ctx.manage_role(1, &admin_kp, operator_kp.pubkey(), OPERATOR));
+    assert!(ctx.manage_role(1, &new_owner_kp, operator_kp.pubkey(), OPERATOR));
+    assert!(ctx.increment(&operator_kp));
+    assert_eq!(ctx.count(), 3);
+}
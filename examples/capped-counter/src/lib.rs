@@ -0,0 +1,64 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitContext {
+    pub payer: Signer,
+    #[constraint(
+        init,
+        payer = payer,
+        space = Counter::SPACE
+    )]
+    pub counter: Mut<Signer<Account<Counter>>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct IncrementContext {
+    pub counter: Incrementable,
+}
+
+handlers! {
+    initialize,
+    increment,
+}
+
+pub fn initialize(_: InitContext) -> Result<(), ProgramError> {
+    Ok(())
+}
+
+pub fn increment(ctx: IncrementContext) -> Result<(), ProgramError> {
+    ctx.counter.mut_data()?.count += 1;
+
+    Ok(())
+}
+
+#[account]
+pub struct Counter {
+    pub count: u64,
+}
+
+impl Counter {
+    const SPACE: usize = std::mem::size_of::<Counter>();
+    pub const MAX: u64 = 3;
+}
+
+/// A counter which has not reached `Counter::MAX` yet.
+#[derive(AccountWrapper)]
+pub struct Incrementable<'a>(Mut<Account<'a, Counter>>);
+
+impl<'a> FromAccountInfo<'a> for Incrementable<'a> {
+    fn try_from_info(info: &'a program::RawAccountInfo) -> Result<Self, ProgramError> {
+        let counter = Mut::<Account<Counter>>::try_from_info(info)?;
+        require!(
+            counter.data()?.count < Counter::MAX,
+            ProgramError::InvalidAccountData
+        );
+
+        Ok(Incrementable(counter))
+    }
+}
@@ -0,0 +1,60 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    program::pubkey::Pubkey,
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitContext {
+    pub admin: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = admin,
+        space = Config::SPACE
+    )]
+    pub config: Mut<Singleton<Config>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct SetFeeContext {
+    pub admin: Signer,
+    pub config: Mut<Singleton<Config>>,
+}
+
+handlers! {
+    initialize,
+    set_fee,
+}
+
+pub fn initialize(fee: Args<u64>, ctx: InitContext) -> Result<(), ProgramError> {
+    let mut config = ctx.config.mut_data()?;
+    config.admin = *ctx.admin.key();
+    config.fee = *fee;
+
+    Ok(())
+}
+
+pub fn set_fee(fee: Args<u64>, ctx: SetFeeContext) -> Result<(), ProgramError> {
+    let mut config = ctx.config.mut_data()?;
+    require_keys_eq!(
+        config.admin,
+        *ctx.admin.key(),
+        ProgramError::InvalidAccountData
+    );
+    config.fee = *fee;
+
+    Ok(())
+}
+
+#[account(singleton)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee: u64,
+}
+
+impl Config {
+    const SPACE: usize = core::mem::size_of::<Config>();
+}
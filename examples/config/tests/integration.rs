@@ -0,0 +1,125 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/config.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn init_ix(program_id: Pubkey, admin: Pubkey, config: Pubkey, fee: u64) -> Instruction {
+    let mut data = vec![0];
+    data.extend_from_slice(&fee.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+fn set_fee_ix(program_id: Pubkey, admin: Pubkey, config: Pubkey, fee: u64) -> Instruction {
+    let mut data = vec![1];
+    data.extend_from_slice(&fee.to_le_bytes());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(admin, true),
+            AccountMeta::new(config, false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    let (config_pk, _) = Pubkey::find_program_address(&[b"Config"], &program_id);
+
+    // Only the derived address can be initialized
+    let other_pk = Pubkey::new_unique();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix(program_id, admin_pk, other_pk, 5)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    assert!(svm.send_transaction(tx).is_err());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix(program_id, admin_pk, config_pk, 5)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&config_pk).unwrap();
+    assert_eq!(account.owner, program_id);
+    assert_eq!(&account.data[..32], admin_pk.as_ref());
+    assert_eq!(account.data[32..], 5u64.to_le_bytes());
+
+    // The config can't be created twice
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix(program_id, admin_pk, config_pk, 7)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    assert!(svm.send_transaction(tx).is_err());
+
+    // Update the fee
+    let tx = Transaction::new_signed_with_payer(
+        &[set_fee_ix(program_id, admin_pk, config_pk, 10)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&config_pk).unwrap();
+    assert_eq!(account.data[32..], 10u64.to_le_bytes());
+
+    // Only the admin can update it
+    let other_kp = Keypair::new();
+    let other_pk = other_kp.pubkey();
+    svm.airdrop(&other_pk, LAMPORTS_PER_SOL).unwrap();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[set_fee_ix(program_id, other_pk, config_pk, 0)],
+        Some(&other_pk),
+        &[&other_kp],
+        hash,
+    );
+    assert!(svm.send_transaction(tx).is_err());
+}
@@ -22,9 +22,16 @@ pub struct IncrementContext {
     pub counter: Mut<Account<Counter>>,
 }
 
+#[context]
+pub struct CloseContext {
+    pub counter: Mut<Account<Counter>>,
+    pub destination: Mut<SystemAccount>,
+}
+
 handlers! {
     initialize,
-    increment
+    increment,
+    close
 }
 
 pub fn initialize(_: InitContext) -> Result<(), ProgramError> {
@@ -37,6 +44,12 @@ pub fn increment(ctx: IncrementContext) -> Result<(), ProgramError> {
     Ok(())
 }
 
+pub fn close(ctx: CloseContext) -> Result<(), ProgramError> {
+    ctx.counter.send_all(&ctx.destination)?;
+
+    Ok(())
+}
+
 #[account]
 pub struct Counter {
     pub count: u64,
@@ -83,4 +83,19 @@ fn integration_test() {
     let raw_account = svm.get_account(&counter_pk).unwrap();
     let counter_account: &Counter = bytemuck::try_from_bytes(raw_account.data.as_slice()).unwrap();
     assert!(counter_account.count == 1);
+
+    // Close the counter
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(counter_pk, false),
+            AccountMeta::new(admin_pk, false),
+        ],
+        data: vec![2],
+    };
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+    svm.send_transaction(tx).unwrap();
+
+    assert_eq!(svm.get_balance(&counter_pk).unwrap_or_default(), 0);
 }
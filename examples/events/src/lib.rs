@@ -0,0 +1,34 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+handlers! {
+    emit_event,
+    emit_event_cpi,
+}
+
+#[context]
+pub struct EventCpiContext {
+    pub event_authority: UncheckedAccount,
+    pub program: Program<EventsProgram>,
+}
+
+pub fn emit_event(amount: Args<u64>) -> Result<(), ProgramError> {
+    emit!(Deposited { amount: *amount });
+
+    Ok(())
+}
+
+pub fn emit_event_cpi(amount: Args<u64>, ctx: EventCpiContext) -> Result<(), ProgramError> {
+    emit_cpi!(Deposited { amount: *amount }, &ctx.event_authority)?;
+
+    Ok(())
+}
+
+#[event]
+pub struct Deposited {
+    pub amount: u64,
+}
@@ -0,0 +1,73 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/events.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // Emit with the logs
+    let amount = 42_u64;
+    let mut data = vec![0];
+    data.extend_from_slice(bytemuck::bytes_of(&amount));
+
+    let ix = Instruction {
+        accounts: vec![],
+        program_id,
+        data,
+    };
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+    let res = svm.send_transaction(tx).unwrap();
+
+    assert!(res.logs[1].starts_with("Program data: "));
+
+    // Emit with a self CPI
+    let (event_authority, _) = Pubkey::find_program_address(&[b"__event_authority"], &program_id);
+    let mut data = vec![1];
+    data.extend_from_slice(bytemuck::bytes_of(&amount));
+
+    let ix = Instruction {
+        accounts: vec![
+            AccountMeta::new_readonly(event_authority, false),
+            AccountMeta::new_readonly(program_id, false),
+        ],
+        program_id,
+        data,
+    };
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+    let res = svm.send_transaction(tx).unwrap();
+
+    let inner_ix = &res.inner_instructions[0][0].instruction;
+    assert_eq!(
+        &inner_ix.data[..8],
+        &0x1d9a_cb51_2ea5_45e4_u64.to_le_bytes()
+    );
+    assert_eq!(&inner_ix.data[16..], bytemuck::bytes_of(&amount));
+}
@@ -0,0 +1,94 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitLegacyContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = CounterV1::SPACE
+    )]
+    pub counter: Mut<Signer<Account<CounterV1>>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = Counter::SPACE
+    )]
+    pub counter: Mut<Signer<Account<Counter>>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct IncrementContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        migrate = CounterV1,
+        payer = payer
+    )]
+    pub counter: Mut<Account<Counter>>,
+    pub system: Program<System>,
+}
+
+handlers! {
+    initialize_legacy,
+    initialize,
+    increment,
+}
+
+/// Creates a counter with the first layout, like the previous release of the program.
+pub fn initialize_legacy(_: InitLegacyContext) -> Result<(), ProgramError> {
+    Ok(())
+}
+
+pub fn initialize(ctx: InitContext) -> Result<(), ProgramError> {
+    ctx.counter.mut_data()?.step = 1;
+
+    Ok(())
+}
+
+/// Increments the counter, upgrading it first if it still has the first layout.
+pub fn increment(ctx: IncrementContext) -> Result<(), ProgramError> {
+    let mut counter = ctx.counter.mut_data()?;
+    counter.count += counter.step;
+
+    Ok(())
+}
+
+#[account(discriminator = b"counter", version = 1)]
+pub struct CounterV1 {
+    pub count: u64,
+}
+
+impl CounterV1 {
+    const SPACE: usize = CounterV1::DISCRIMINATOR.len() + core::mem::size_of::<CounterV1>();
+}
+
+#[account(discriminator = b"counter", version = 2)]
+pub struct Counter {
+    pub count: u64,
+    pub step: u64,
+}
+
+impl Counter {
+    const SPACE: usize = Counter::DISCRIMINATOR.len() + core::mem::size_of::<Counter>();
+}
+
+impl Migrate<CounterV1> for Counter {
+    fn migrate(from: &CounterV1) -> Self {
+        Counter {
+            count: from.count,
+            step: 1,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/migration.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn ix(program_id: Pubkey, payer: Pubkey, counter: Pubkey, discriminator: u8) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(counter, discriminator != 2),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![discriminator],
+    }
+}
+
+fn counter_data(version: u8, count: u64, step: Option<u64>) -> Vec<u8> {
+    let mut data = b"counter".to_vec();
+    data.push(version);
+    data.extend_from_slice(&count.to_le_bytes());
+    if let Some(step) = step {
+        data.extend_from_slice(&step.to_le_bytes());
+    }
+
+    data
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // Create a counter with the first layout
+    let legacy_kp = Keypair::new();
+    let legacy_pk = legacy_kp.pubkey();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix(program_id, admin_pk, legacy_pk, 0)],
+        Some(&admin_pk),
+        &[&admin_kp, &legacy_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&legacy_pk).unwrap();
+    assert_eq!(account.data, counter_data(1, 0, None));
+
+    // Incrementing migrates it to the second layout, the payer topping up the rent
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix(program_id, admin_pk, legacy_pk, 2)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&legacy_pk).unwrap();
+    assert_eq!(account.data, counter_data(2, 1, Some(1)));
+    assert_eq!(account.lamports, svm.minimum_balance_for_rent_exemption(24));
+
+    // Migrated accounts are loaded as they are
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix(program_id, admin_pk, legacy_pk, 2)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&legacy_pk).unwrap();
+    assert_eq!(account.data, counter_data(2, 2, Some(1)));
+
+    // New counters are created with the version byte of the current layout
+    let counter_kp = Keypair::new();
+    let counter_pk = counter_kp.pubkey();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            ix(program_id, admin_pk, counter_pk, 1),
+            ix(program_id, admin_pk, counter_pk, 2),
+        ],
+        Some(&admin_pk),
+        &[&admin_kp, &counter_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&counter_pk).unwrap();
+    assert_eq!(account.data, counter_data(2, 1, Some(1)));
+
+    // Another account of the program with the same version byte isn't migrated
+    let other_pk = Pubkey::new_unique();
+    let mut other_data = b"another".to_vec();
+    other_data.push(1);
+    other_data.extend_from_slice(&5u64.to_le_bytes());
+    svm.set_account(
+        other_pk,
+        Account {
+            lamports: LAMPORTS_PER_SOL,
+            data: other_data.clone(),
+            owner: program_id,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix(program_id, admin_pk, other_pk, 2)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    assert!(svm.send_transaction(tx).is_err());
+
+    let account = svm.get_account(&other_pk).unwrap();
+    assert_eq!(account.data, other_data);
+}
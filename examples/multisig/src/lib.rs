@@ -0,0 +1,62 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    program::pubkey::Pubkey,
+    typhoon::prelude::*,
+};
+
+program_id!("4jVBJY1YvpSXjRPcWu8q3HfB9HDJcg8JiZRQa5tx1bsi");
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = core::mem::size_of::<Treasury>()
+    )]
+    pub treasury: Mut<Signer<Account<Treasury>>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct WithdrawContext {
+    pub recipient: Mut<SystemAccount>,
+}
+
+handlers! {
+    initialize,
+    withdraw,
+}
+
+pub fn initialize(config: Args<Treasury>, ctx: InitContext) -> Result<(), ProgramError> {
+    *ctx.treasury.mut_data()? = *config;
+
+    Ok(())
+}
+
+/// Sends lamports of the treasury once enough of its members signed. The threshold is only
+/// checked because the treasury is taken as a `Multisig`, which every handler paying from it has
+/// to do.
+pub fn withdraw(
+    amount: Args<u64>,
+    ctx: WithdrawContext,
+    treasury: Multisig<Mut<Account<Treasury>>>,
+) -> Result<(), ProgramError> {
+    treasury.send_rent_exempt(&ctx.recipient, *amount)
+}
+
+#[account]
+pub struct Treasury {
+    pub threshold: u8,
+    pub members: [Pubkey; 3],
+}
+
+impl MultisigData for Treasury {
+    fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    fn members(&self) -> &[Pubkey] {
+        &self.members
+    }
+}
@@ -0,0 +1,223 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/multisig.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn init_ix(
+    program_id: Pubkey,
+    payer: Pubkey,
+    treasury: Pubkey,
+    threshold: u8,
+    members: &[Pubkey; 3],
+) -> Instruction {
+    let mut data = vec![0, threshold];
+    for member in members {
+        data.extend_from_slice(member.as_ref());
+    }
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(treasury, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+fn withdraw_ix(
+    program_id: Pubkey,
+    recipient: Pubkey,
+    treasury: Pubkey,
+    signers: &[Pubkey],
+    amount: u64,
+) -> Instruction {
+    let mut data = vec![1];
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    let mut accounts = vec![
+        AccountMeta::new(recipient, false),
+        AccountMeta::new(treasury, false),
+    ];
+    accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    Instruction {
+        program_id,
+        accounts,
+        data,
+    }
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let members_kp = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let members_pk = members_kp.each_ref().map(|kp| kp.pubkey());
+    let outsider_kp = Keypair::new();
+
+    for kp in members_kp.iter().chain([&outsider_kp]) {
+        svm.airdrop(&kp.pubkey(), 10 * LAMPORTS_PER_SOL).unwrap();
+    }
+
+    let program_id = pubkey!("4jVBJY1YvpSXjRPcWu8q3HfB9HDJcg8JiZRQa5tx1bsi");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // Create a treasury spending with 2 of its 3 members
+    let treasury_kp = Keypair::new();
+    let treasury_pk = treasury_kp.pubkey();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[init_ix(
+            program_id,
+            members_pk[0],
+            treasury_pk,
+            2,
+            &members_pk,
+        )],
+        Some(&members_pk[0]),
+        &[&members_kp[0], &treasury_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    svm.airdrop(&treasury_pk, LAMPORTS_PER_SOL).unwrap();
+
+    let recipient_pk = Pubkey::new_unique();
+    let mut withdraw = |signers: &[&Keypair], amount: u64| {
+        svm.expire_blockhash();
+        let signers_pk: Vec<Pubkey> = signers.iter().map(|kp| kp.pubkey()).collect();
+        let mut keypairs = signers.to_vec();
+        keypairs.dedup_by_key(|kp| kp.pubkey());
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_ix(
+                program_id,
+                recipient_pk,
+                treasury_pk,
+                &signers_pk,
+                amount,
+            )],
+            Some(&signers_pk[0]),
+            keypairs.as_slice(),
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).is_ok()
+    };
+
+    // One member isn't enough, even when given twice or with someone else
+    assert!(!withdraw(&[&members_kp[0]], LAMPORTS_PER_SOL / 2));
+    assert!(!withdraw(
+        &[&members_kp[0], &members_kp[0]],
+        LAMPORTS_PER_SOL / 2
+    ));
+    assert!(!withdraw(
+        &[&members_kp[0], &outsider_kp],
+        LAMPORTS_PER_SOL / 2
+    ));
+
+    assert!(withdraw(
+        &[&members_kp[0], &members_kp[2]],
+        LAMPORTS_PER_SOL / 2
+    ));
+
+    // The treasury stays rent-exempt
+    assert!(!withdraw(
+        &[&members_kp[1], &members_kp[2]],
+        LAMPORTS_PER_SOL
+    ));
+
+    assert_eq!(svm.get_balance(&recipient_pk), Some(LAMPORTS_PER_SOL / 2));
+}
+
+#[test]
+fn duplicated_members() {
+    let mut svm = LiteSVM::new();
+    let member_a_kp = Keypair::new();
+    let member_b_kp = Keypair::new();
+
+    for kp in [&member_a_kp, &member_b_kp] {
+        svm.airdrop(&kp.pubkey(), 10 * LAMPORTS_PER_SOL).unwrap();
+    }
+
+    let program_id = pubkey!("4jVBJY1YvpSXjRPcWu8q3HfB9HDJcg8JiZRQa5tx1bsi");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    let member_a_pk = member_a_kp.pubkey();
+    let member_b_pk = member_b_kp.pubkey();
+    let recipient_pk = Pubkey::new_unique();
+    let mut treasuries = Vec::new();
+
+    // The first member is listed twice, and the second treasury asks more members than it has
+    for threshold in [2, 4] {
+        let treasury_kp = Keypair::new();
+        let hash = svm.latest_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[init_ix(
+                program_id,
+                member_a_pk,
+                treasury_kp.pubkey(),
+                threshold,
+                &[member_a_pk, member_a_pk, member_b_pk],
+            )],
+            Some(&member_a_pk),
+            &[&member_a_kp, &treasury_kp],
+            hash,
+        );
+        svm.send_transaction(tx).unwrap();
+        svm.airdrop(&treasury_kp.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        treasuries.push(treasury_kp.pubkey());
+    }
+
+    let mut withdraw = |treasury_pk: Pubkey, signers: &[&Keypair]| {
+        svm.expire_blockhash();
+        let signers_pk: Vec<Pubkey> = signers.iter().map(|kp| kp.pubkey()).collect();
+        let tx = Transaction::new_signed_with_payer(
+            &[withdraw_ix(
+                program_id,
+                recipient_pk,
+                treasury_pk,
+                &signers_pk,
+                LAMPORTS_PER_SOL / 4,
+            )],
+            Some(&signers_pk[0]),
+            signers,
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).is_ok()
+    };
+
+    // A member listed twice approves once
+    assert!(!withdraw(treasuries[0], &[&member_a_kp]));
+    assert!(withdraw(treasuries[0], &[&member_a_kp, &member_b_kp]));
+
+    // A threshold above the number of members is never met
+    assert!(!withdraw(treasuries[1], &[&member_a_kp, &member_b_kp]));
+}
@@ -0,0 +1,152 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    program::sysvars::{
+        clock::Clock, epoch_schedule::EpochSchedule, rent::Rent, slot_hashes::SlotHashes,
+    },
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+handlers! {
+    check_sysvars,
+    check_slot_hash,
+    check_preceding_transfer,
+    verify_signature,
+    verify_eth_signature,
+}
+
+#[context]
+pub struct SysvarContext {
+    pub clock: Sysvar<Clock>,
+    pub rent: Sysvar<Rent>,
+    pub epoch_schedule: Sysvar<EpochSchedule>,
+}
+
+#[context]
+pub struct SlotHashesContext {
+    pub slot_hashes: Sysvar<SlotHashes>,
+}
+
+#[context]
+pub struct IntrospectionContext {
+    pub instructions: InstructionsSysvar,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct SignedMessage {
+    pub signer: [u8; 32],
+    pub message: [u8; 32],
+    pub signature: [u8; 64],
+}
+
+#[context]
+#[args(SignedMessage)]
+pub struct SignatureContext {
+    pub instructions: InstructionsSysvar,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct EthSignedMessage {
+    pub eth_address: [u8; 20],
+    pub message: [u8; 32],
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+}
+
+#[context]
+#[args(EthSignedMessage)]
+pub struct EthSignatureContext {
+    pub instructions: InstructionsSysvar,
+}
+
+/// Reads the sysvars from their accounts and checks they match the syscalls.
+pub fn check_sysvars(ctx: SysvarContext) -> Result<(), ProgramError> {
+    let clock = ctx.clock.data()?;
+    require!(*clock == Clock::get()?, ProgramError::InvalidAccountData);
+
+    let rent = ctx.rent.data()?;
+    require!(*rent == Rent::get()?, ProgramError::InvalidAccountData);
+    require!(
+        rent.is_exempt(rent.minimum_balance(0), 0),
+        ProgramError::InvalidAccountData
+    );
+
+    let epoch_schedule = ctx.epoch_schedule.data()?;
+    require!(
+        *epoch_schedule == EpochSchedule::get()?,
+        ProgramError::InvalidAccountData
+    );
+    require_eq!(
+        epoch_schedule.get_epoch(clock.slot),
+        clock.epoch,
+        ProgramError::InvalidAccountData
+    );
+
+    Ok(())
+}
+
+/// Checks the hash of a recent slot is still available.
+pub fn check_slot_hash(slot: Args<u64>, ctx: SlotHashesContext) -> Result<(), ProgramError> {
+    let entries = ctx.slot_hashes.entries()?;
+    require_eq!(
+        entries.len(),
+        ctx.slot_hashes.data()?.len(),
+        ProgramError::InvalidAccountData
+    );
+
+    ctx.slot_hashes
+        .get_hash(*slot)?
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    Ok(())
+}
+
+/// Requires the program to be called by the transaction, right after a System transfer.
+pub fn check_preceding_transfer(ctx: IntrospectionContext) -> Result<(), ProgramError> {
+    ctx.instructions.assert_top_level(&crate::ID)?;
+
+    let previous = ctx.instructions.get_instruction_relative(-1)?;
+    require_keys_eq!(
+        *previous.program_id(),
+        program::system_program::ID,
+        ProgramError::InvalidInstructionData
+    );
+    require!(
+        previous.data().starts_with(&2u32.to_le_bytes()),
+        ProgramError::InvalidInstructionData
+    );
+
+    Ok(())
+}
+
+/// Requires an Ed25519 instruction of the transaction to verify the signed message.
+pub fn verify_signature(ctx: SignatureContext) -> Result<(), ProgramError> {
+    verify_ed25519_ix(
+        &ctx.instructions,
+        &program::pubkey_from_array(ctx.args.signer),
+        &ctx.args.message,
+        &ctx.args.signature,
+    )
+}
+
+/// Requires a secp256k1 instruction of the transaction to verify the signed message, and recovers
+/// the same signer with the syscall.
+pub fn verify_eth_signature(ctx: EthSignatureContext) -> Result<(), ProgramError> {
+    let args = &ctx.args;
+    verify_secp256k1_ix(
+        &ctx.instructions,
+        &args.eth_address,
+        &args.message,
+        &args.signature,
+        args.recovery_id,
+    )?;
+
+    let hash = program::crypto::keccak256(&[&args.message]);
+    let signer = program::secp256k1::recover_eth_address(&hash, args.recovery_id, &args.signature)?;
+    require!(signer == args.eth_address, ProgramError::InvalidArgument);
+
+    Ok(())
+}
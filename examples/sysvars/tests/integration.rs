@@ -0,0 +1,216 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        hash::Hash,
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        secp256k1_instruction,
+        signature::Keypair,
+        signer::Signer,
+        slot_hashes::SlotHashes,
+        system_instruction, sysvar,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/sysvars.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // Read the sysvar accounts
+
+    let ix = Instruction {
+        accounts: vec![
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(sysvar::epoch_schedule::ID, false),
+        ],
+        program_id,
+        data: vec![0],
+    };
+
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    // Wrong sysvar address
+
+    let ix = Instruction {
+        accounts: vec![
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(sysvar::epoch_schedule::ID, false),
+        ],
+        program_id,
+        data: vec![0],
+    };
+
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_err());
+
+    // Look up slot hashes
+
+    svm.set_sysvar(&SlotHashes::new(&[
+        (5, Hash::new_unique()),
+        (3, Hash::new_unique()),
+        (1, Hash::new_unique()),
+    ]));
+
+    for (slot, found) in [(3u64, true), (4, false)] {
+        let mut data = vec![1];
+        data.extend_from_slice(&slot.to_le_bytes());
+
+        let ix = Instruction {
+            accounts: vec![AccountMeta::new_readonly(sysvar::slot_hashes::ID, false)],
+            program_id,
+            data,
+        };
+
+        svm.expire_blockhash();
+        let hash = svm.latest_blockhash();
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+
+        let res = svm.send_transaction(tx);
+        assert_eq!(res.is_ok(), found);
+    }
+
+    // Require a preceding transfer
+
+    let ix = Instruction {
+        accounts: vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        program_id,
+        data: vec![2],
+    };
+    let transfer_ix =
+        system_instruction::transfer(&admin_pk, &Pubkey::new_unique(), LAMPORTS_PER_SOL);
+
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix, ix.clone()],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_err());
+
+    // Verify an Ed25519 signature
+
+    let signer_kp = Keypair::new();
+    let message = [7u8; 32];
+    let signature = signer_kp.sign_message(&message);
+
+    let mut ed25519_data = vec![1, 0];
+    for offset in [48u16, u16::MAX, 16, u16::MAX, 112, 32, u16::MAX] {
+        ed25519_data.extend_from_slice(&offset.to_le_bytes());
+    }
+    ed25519_data.extend_from_slice(signer_kp.pubkey().as_ref());
+    ed25519_data.extend_from_slice(signature.as_ref());
+    ed25519_data.extend_from_slice(&message);
+
+    let ed25519_ix = Instruction {
+        accounts: vec![],
+        program_id: solana_sdk::ed25519_program::ID,
+        data: ed25519_data,
+    };
+
+    for (signed_message, verified) in [(message, true), ([8u8; 32], false)] {
+        let mut data = vec![3];
+        data.extend_from_slice(signer_kp.pubkey().as_ref());
+        data.extend_from_slice(&signed_message);
+        data.extend_from_slice(signature.as_ref());
+
+        let ix = Instruction {
+            accounts: vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+            program_id,
+            data,
+        };
+
+        svm.expire_blockhash();
+        let hash = svm.latest_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[ed25519_ix.clone(), ix],
+            Some(&admin_pk),
+            &[&admin_kp],
+            hash,
+        );
+
+        let res = svm.send_transaction(tx);
+        assert_eq!(res.is_ok(), verified);
+    }
+
+    // Verify a secp256k1 signature, the precompile instruction reading its data from itself at
+    // index 0
+
+    let secret_key = libsecp256k1::SecretKey::parse(&[1u8; 32]).unwrap();
+    let eth_address = secp256k1_instruction::construct_eth_pubkey(
+        &libsecp256k1::PublicKey::from_secret_key(&secret_key),
+    );
+    let secp256k1_ix = secp256k1_instruction::new_secp256k1_instruction(&secret_key, &message);
+    // Laid out as the offsets, the address, the signature, the recovery id and the message
+    let signature = &secp256k1_ix.data[32..96];
+    let recovery_id = secp256k1_ix.data[96];
+
+    for (signed_address, signed_message, verified) in [
+        (eth_address, message, true),
+        (eth_address, [8u8; 32], false),
+        ([9u8; 20], message, false),
+    ] {
+        let mut data = vec![4];
+        data.extend_from_slice(&signed_address);
+        data.extend_from_slice(&signed_message);
+        data.extend_from_slice(signature);
+        data.push(recovery_id);
+
+        let ix = Instruction {
+            accounts: vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+            program_id,
+            data,
+        };
+
+        svm.expire_blockhash();
+        let hash = svm.latest_blockhash();
+        let tx = Transaction::new_signed_with_payer(
+            &[secp256k1_ix.clone(), ix],
+            Some(&admin_pk),
+            &[&admin_kp],
+            hash,
+        );
+
+        let res = svm.send_transaction(tx);
+        assert_eq!(res.is_ok(), verified);
+    }
+}
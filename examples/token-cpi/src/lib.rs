@@ -0,0 +1,228 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    program::sysvars::clock::Clock,
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+handlers! {
+    mint_to,
+    transfer,
+    withdraw,
+    burn,
+    close,
+    initialize,
+    open_associated_account,
+    transfer_interface,
+    check_accounts,
+}
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    pub vault_authority: UncheckedAccount,
+    #[constraint(
+        init,
+        payer = payer,
+        mint::decimals = 6,
+        mint::authority = payer
+    )]
+    pub mint: Mut<Mint>,
+    #[constraint(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = vault_authority
+    )]
+    pub vault: Mut<TokenAccount>,
+    pub system: Program<System>,
+    pub token_program: Program<TokenProgram>,
+}
+
+#[context]
+pub struct AssociatedContext {
+    pub payer: Mut<Signer>,
+    pub mint: Mint,
+    #[constraint(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer
+    )]
+    pub account: Mut<TokenAccount>,
+    pub system: Program<System>,
+    pub token_program: Program<TokenProgram>,
+    pub associated_token_program: Program<AssociatedTokenProgram>,
+}
+
+#[context]
+pub struct MintToContext {
+    pub mint: Mut<Mint>,
+    pub destination: Mut<TokenAccount>,
+    pub authority: Signer,
+}
+
+#[context]
+pub struct TransferContext {
+    pub from: Mut<TokenAccount>,
+    pub mint: Mint,
+    pub to: Mut<TokenAccount>,
+    pub authority: Signer,
+}
+
+#[context]
+pub struct VaultContext {
+    pub vault: Mut<TokenAccount>,
+    pub mint: Mint,
+    #[constraint(token::mint = mint)]
+    pub to: Mut<TokenAccount>,
+    pub vault_authority: UncheckedAccount,
+}
+
+#[context]
+pub struct BurnContext {
+    pub account: Mut<TokenAccount>,
+    pub mint: Mut<Mint>,
+    pub authority: Signer,
+}
+
+#[context]
+pub struct CloseContext {
+    pub account: Mut<TokenAccount>,
+    pub destination: Mut<SystemAccount>,
+    pub authority: Signer,
+}
+
+#[context]
+#[args(amount: u64, expected_fee: u64)]
+pub struct InterfaceTransferContext {
+    pub from: Mut<InterfaceAccount<TokenAccountData>>,
+    pub mint: InterfaceAccount<MintData>,
+    #[constraint(token::mint = mint)]
+    pub to: Mut<InterfaceAccount<TokenAccountData>>,
+    pub authority: Signer,
+    pub token_program: Interface<TokenInterface>,
+}
+
+#[context]
+pub struct CheckContext {
+    pub authority: UncheckedAccount,
+    #[constraint(
+        mint::decimals = 6,
+        mint::authority = authority
+    )]
+    pub mint: Mint,
+    #[constraint(
+        token::mint = mint,
+        token::authority = authority
+    )]
+    pub account: TokenAccount,
+}
+
+#[context]
+pub struct TokenProgramContext {
+    pub token_program: Program<TokenProgram>,
+}
+
+pub fn mint_to(
+    amount: Args<u64>,
+    ctx: MintToContext,
+    _: TokenProgramContext,
+) -> Result<(), ProgramError> {
+    ctx.mint
+        .mint_to(&ctx.destination, &ctx.authority, *amount, None)?;
+
+    Ok(())
+}
+
+pub fn transfer(
+    amount: Args<u64>,
+    ctx: TransferContext,
+    _: TokenProgramContext,
+) -> Result<(), ProgramError> {
+    let decimals = ctx.mint.data()?.decimals();
+
+    ctx.from
+        .transfer_checked(&ctx.mint, &ctx.to, &ctx.authority, *amount, decimals, None)?;
+
+    Ok(())
+}
+
+pub fn withdraw(
+    amount: Args<u64>,
+    ctx: VaultContext,
+    _: TokenProgramContext,
+) -> Result<(), ProgramError> {
+    let (authority, bump) = program::find_program_address(&[b"authority"], &crate::ID);
+    require_keys_eq!(
+        *ctx.vault_authority.key(),
+        authority,
+        ProgramError::InvalidSeeds
+    );
+
+    let decimals = ctx.mint.data()?.decimals();
+
+    ctx.vault.transfer_checked(
+        &ctx.mint,
+        &ctx.to,
+        &ctx.vault_authority,
+        *amount,
+        decimals,
+        Some(&[program::signer!(b"authority", &[bump])]),
+    )?;
+
+    Ok(())
+}
+
+pub fn burn(
+    amount: Args<u64>,
+    ctx: BurnContext,
+    _: TokenProgramContext,
+) -> Result<(), ProgramError> {
+    ctx.account.burn(&ctx.mint, &ctx.authority, *amount, None)?;
+
+    Ok(())
+}
+
+pub fn close(ctx: CloseContext, _: TokenProgramContext) -> Result<(), ProgramError> {
+    ctx.account
+        .close_account(&ctx.destination, &ctx.authority, None)?;
+
+    Ok(())
+}
+
+pub fn initialize(_: InitContext) -> Result<(), ProgramError> {
+    Ok(())
+}
+
+pub fn open_associated_account(_: AssociatedContext) -> Result<(), ProgramError> {
+    Ok(())
+}
+
+pub fn transfer_interface(ctx: InterfaceTransferContext) -> Result<(), ProgramError> {
+    let decimals = ctx.mint.data()?.decimals();
+    let fee = match ctx.mint.extension::<extensions::TransferFeeConfig>()? {
+        Some(config) => config
+            .get_epoch_fee(Clock::get()?.epoch)
+            .calculate_fee(ctx.args.amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?,
+        None => 0,
+    };
+    require_eq!(fee, ctx.args.expected_fee, ProgramError::InvalidArgument);
+
+    ctx.from.transfer_checked(
+        &ctx.mint,
+        &ctx.to,
+        &ctx.authority,
+        ctx.args.amount,
+        decimals,
+        None,
+    )?;
+
+    Ok(())
+}
+
+pub fn check_accounts(_: CheckContext) -> Result<(), ProgramError> {
+    Ok(())
+}
@@ -0,0 +1,481 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        account::Account,
+        instruction::{AccountMeta, Instruction, InstructionError},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_instruction, system_program,
+        transaction::{Transaction, TransactionError},
+    },
+    std::path::PathBuf,
+    typhoon::{lib::AssociatedTokenProgram, program},
+};
+
+const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const MINT_LEN: usize = 82;
+const ACCOUNT_LEN: usize = 165;
+// Base state padded to an account, the account type and the TLV entry of the fee extension.
+const MINT_WITH_FEE_LEN: usize = 166 + 4 + 108;
+const ACCOUNT_WITH_FEE_LEN: usize = 166 + 4 + 8;
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/token_cpi.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn send(svm: &mut LiteSVM, ixs: &[Instruction], payer: &Keypair, signers: &[&Keypair]) {
+    let hash = svm.latest_blockhash();
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, hash);
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_ok(), "{res:?}");
+}
+
+fn create_token_account(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    account: &Keypair,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) {
+    create_token_account_with(
+        svm,
+        payer,
+        account,
+        mint,
+        owner,
+        &TOKEN_PROGRAM_ID,
+        ACCOUNT_LEN,
+    );
+}
+
+fn create_token_account_with(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    account: &Keypair,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    token_program: &Pubkey,
+    len: usize,
+) {
+    let lamports = svm.minimum_balance_for_rent_exemption(len);
+    let mut data = vec![18];
+    data.extend_from_slice(owner.as_ref());
+
+    let ixs = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &account.pubkey(),
+            lamports,
+            len as u64,
+            token_program,
+        ),
+        Instruction {
+            program_id: *token_program,
+            accounts: vec![
+                AccountMeta::new(account.pubkey(), false),
+                AccountMeta::new_readonly(*mint, false),
+            ],
+            data,
+        },
+    ];
+    send(svm, &ixs, payer, &[account]);
+}
+
+fn create_mint(svm: &mut LiteSVM, payer: &Keypair, mint: &Keypair, decimals: u8) {
+    let lamports = svm.minimum_balance_for_rent_exemption(MINT_LEN);
+    let mut data = vec![20, decimals];
+    data.extend_from_slice(payer.pubkey().as_ref());
+    data.push(0);
+
+    let ixs = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            lamports,
+            MINT_LEN as u64,
+            &TOKEN_PROGRAM_ID,
+        ),
+        Instruction {
+            program_id: TOKEN_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(mint.pubkey(), false)],
+            data,
+        },
+    ];
+    send(svm, &ixs, payer, &[mint]);
+}
+
+fn read_u64(svm: &LiteSVM, key: &Pubkey, offset: usize) -> u64 {
+    let account = svm.get_account(key).unwrap();
+
+    u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap())
+}
+
+fn ix_data(discriminator: u8, amount: u64) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    data.extend_from_slice(&amount.to_le_bytes());
+    data
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    let (vault_authority, _) = Pubkey::find_program_address(&[b"authority"], &program_id);
+
+    let mint_kp = Keypair::new();
+    let mint_pk = mint_kp.pubkey();
+    let admin_account_kp = Keypair::new();
+    let admin_account_pk = admin_account_kp.pubkey();
+    let vault_kp = Keypair::new();
+    let vault_pk = vault_kp.pubkey();
+    let recipient_kp = Keypair::new();
+    let recipient_pk = recipient_kp.pubkey();
+
+    // Initialize the mint and the vault with the constraints
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pk, true),
+            AccountMeta::new_readonly(vault_authority, false),
+            AccountMeta::new(mint_pk, true),
+            AccountMeta::new(vault_pk, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![5],
+    };
+    send(&mut svm, &[ix], &admin_kp, &[&mint_kp, &vault_kp]);
+
+    let mint = svm.get_account(&mint_pk).unwrap();
+    let vault = svm.get_account(&vault_pk).unwrap();
+    assert_eq!(mint.owner, TOKEN_PROGRAM_ID);
+    assert_eq!(mint.data[44], 6);
+    assert_eq!(&mint.data[4..36], admin_pk.as_ref());
+    assert_eq!(&vault.data[..32], mint_pk.as_ref());
+    assert_eq!(&vault.data[32..64], vault_authority.as_ref());
+
+    create_token_account(&mut svm, &admin_kp, &admin_account_kp, &mint_pk, &admin_pk);
+    create_token_account(&mut svm, &admin_kp, &recipient_kp, &mint_pk, &admin_pk);
+
+    // Mint to
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(mint_pk, false),
+            AccountMeta::new(admin_account_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: ix_data(0, 1_000),
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    assert_eq!(read_u64(&svm, &admin_account_pk, 64), 1_000);
+    assert_eq!(read_u64(&svm, &mint_pk, 36), 1_000);
+
+    // Transfer to the vault
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_account_pk, false),
+            AccountMeta::new_readonly(mint_pk, false),
+            AccountMeta::new(vault_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: ix_data(1, 400),
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    assert_eq!(read_u64(&svm, &admin_account_pk, 64), 600);
+    assert_eq!(read_u64(&svm, &vault_pk, 64), 400);
+
+    // Withdraw from the vault with the PDA authority
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(vault_pk, false),
+            AccountMeta::new_readonly(mint_pk, false),
+            AccountMeta::new(recipient_pk, false),
+            AccountMeta::new_readonly(vault_authority, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: ix_data(2, 150),
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    assert_eq!(read_u64(&svm, &vault_pk, 64), 250);
+    assert_eq!(read_u64(&svm, &recipient_pk, 64), 150);
+
+    // Burn
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_account_pk, false),
+            AccountMeta::new(mint_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: ix_data(3, 600),
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    assert_eq!(read_u64(&svm, &admin_account_pk, 64), 0);
+    assert_eq!(read_u64(&svm, &mint_pk, 36), 400);
+
+    // Close the empty account
+
+    let destination_pk = Pubkey::new_unique();
+    let rent = svm.get_balance(&admin_account_pk).unwrap_or_default();
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_account_pk, false),
+            AccountMeta::new(destination_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![4],
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    let balance = svm.get_balance(&admin_account_pk).unwrap_or_default();
+    let destination_balance = svm.get_balance(&destination_pk).unwrap_or_default();
+    assert_eq!(balance, 0);
+    assert_eq!(destination_balance, rent);
+
+    // Open the associated token account, twice to go through `init_if_needed`
+
+    let (associated_pk, _) = AssociatedTokenProgram::find_address(
+        &program::pubkey::Pubkey::new_from_array(admin_pk.to_bytes()),
+        &program::pubkey::Pubkey::new_from_array(mint_pk.to_bytes()),
+        &program::pubkey::Pubkey::new_from_array(TOKEN_PROGRAM_ID.to_bytes()),
+    );
+    let associated_pk = Pubkey::new_from_array(associated_pk.to_bytes());
+    let open_ix = |account: Pubkey| Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pk, true),
+            AccountMeta::new_readonly(mint_pk, false),
+            AccountMeta::new(account, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: vec![6],
+    };
+
+    send(&mut svm, &[open_ix(associated_pk)], &admin_kp, &[]);
+
+    let associated = svm.get_account(&associated_pk).unwrap();
+    assert_eq!(associated.owner, TOKEN_PROGRAM_ID);
+    assert_eq!(&associated.data[..32], mint_pk.as_ref());
+    assert_eq!(&associated.data[32..64], admin_pk.as_ref());
+
+    svm.expire_blockhash();
+    send(&mut svm, &[open_ix(associated_pk)], &admin_kp, &[]);
+
+    // A token account which is not the associated one is rejected
+
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[open_ix(recipient_pk)],
+        Some(&admin_pk),
+        &[&admin_kp],
+        hash,
+    );
+    assert!(svm.send_transaction(tx).is_err());
+
+    // Transfer a Token-2022 token with a transfer fee through the interface accounts
+
+    let mint_2022_kp = Keypair::new();
+    let mint_2022_pk = mint_2022_kp.pubkey();
+    let lamports = svm.minimum_balance_for_rent_exemption(MINT_WITH_FEE_LEN);
+
+    let mut fee_config_data = vec![26, 0, 1];
+    fee_config_data.extend_from_slice(admin_pk.as_ref());
+    fee_config_data.push(1);
+    fee_config_data.extend_from_slice(admin_pk.as_ref());
+    fee_config_data.extend_from_slice(&100u16.to_le_bytes());
+    fee_config_data.extend_from_slice(&u64::MAX.to_le_bytes());
+
+    let mut mint_data = vec![20, 6];
+    mint_data.extend_from_slice(admin_pk.as_ref());
+    mint_data.push(0);
+
+    let ixs = [
+        system_instruction::create_account(
+            &admin_pk,
+            &mint_2022_pk,
+            lamports,
+            MINT_WITH_FEE_LEN as u64,
+            &TOKEN_2022_PROGRAM_ID,
+        ),
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(mint_2022_pk, false)],
+            data: fee_config_data,
+        },
+        Instruction {
+            program_id: TOKEN_2022_PROGRAM_ID,
+            accounts: vec![AccountMeta::new(mint_2022_pk, false)],
+            data: mint_data,
+        },
+    ];
+    send(&mut svm, &ixs, &admin_kp, &[&mint_2022_kp]);
+
+    let from_2022_kp = Keypair::new();
+    let from_2022_pk = from_2022_kp.pubkey();
+    let to_2022_kp = Keypair::new();
+    let to_2022_pk = to_2022_kp.pubkey();
+
+    for account in [&from_2022_kp, &to_2022_kp] {
+        create_token_account_with(
+            &mut svm,
+            &admin_kp,
+            account,
+            &mint_2022_pk,
+            &admin_pk,
+            &TOKEN_2022_PROGRAM_ID,
+            ACCOUNT_WITH_FEE_LEN,
+        );
+    }
+
+    let ix = Instruction {
+        program_id: TOKEN_2022_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(mint_2022_pk, false),
+            AccountMeta::new(from_2022_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+        ],
+        data: ix_data(7, 1_000),
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    let mut data = ix_data(7, 1_000);
+    data.extend_from_slice(&10u64.to_le_bytes());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(from_2022_pk, false),
+            AccountMeta::new_readonly(mint_2022_pk, false),
+            AccountMeta::new(to_2022_pk, false),
+            AccountMeta::new_readonly(admin_pk, true),
+            AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+        ],
+        data,
+    };
+    send(&mut svm, &[ix], &admin_kp, &[]);
+
+    assert_eq!(read_u64(&svm, &from_2022_pk, 64), 0);
+    assert_eq!(read_u64(&svm, &to_2022_pk, 64), 990);
+    // The fee is withheld in the `TransferFeeAmount` extension of the recipient
+    assert_eq!(read_u64(&svm, &to_2022_pk, 170), 10);
+
+    // The constraints of existing token accounts and mints are checked
+
+    let other_mint_kp = Keypair::new();
+    let other_mint_pk = other_mint_kp.pubkey();
+    let nine_decimals_kp = Keypair::new();
+    let nine_decimals_pk = nine_decimals_kp.pubkey();
+    create_mint(&mut svm, &admin_kp, &other_mint_kp, 6);
+    create_mint(&mut svm, &admin_kp, &nine_decimals_kp, 9);
+
+    // Owned by the Token program with the right length, but never initialized
+    let uninitialized_mint_pk = Pubkey::new_unique();
+    let uninitialized_account_pk = Pubkey::new_unique();
+    for (key, len) in [
+        (uninitialized_mint_pk, MINT_LEN),
+        (uninitialized_account_pk, ACCOUNT_LEN),
+    ] {
+        svm.set_account(
+            key,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(len),
+                data: vec![0; len],
+                owner: TOKEN_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    let mut check = |authority: Pubkey, mint: Pubkey, account: Pubkey| {
+        svm.expire_blockhash();
+        let ix = Instruction {
+            program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(authority, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(account, false),
+            ],
+            data: vec![8],
+        };
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&admin_pk),
+            &[&admin_kp],
+            svm.latest_blockhash(),
+        );
+
+        svm.send_transaction(tx).map(|_| ()).map_err(|err| err.err)
+    };
+    let custom = |code| {
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code),
+        ))
+    };
+
+    assert_eq!(check(admin_pk, mint_pk, recipient_pk), Ok(()));
+    // TokenMintMismatch
+    assert_eq!(check(admin_pk, other_mint_pk, recipient_pk), custom(3006));
+    // TokenOwnerMismatch
+    assert_eq!(check(admin_pk, mint_pk, vault_pk), custom(3007));
+    // MintAuthorityMismatch
+    assert_eq!(check(vault_authority, mint_pk, vault_pk), custom(3008));
+    // MintDecimalsMismatch
+    assert_eq!(
+        check(admin_pk, nine_decimals_pk, recipient_pk),
+        custom(3009)
+    );
+    let uninitialized = Err(TransactionError::InstructionError(
+        0,
+        InstructionError::UninitializedAccount,
+    ));
+    assert_eq!(
+        check(admin_pk, uninitialized_mint_pk, recipient_pk),
+        uninitialized
+    );
+    assert_eq!(
+        check(admin_pk, mint_pk, uninitialized_account_pk),
+        uninitialized
+    );
+}
@@ -7,7 +7,8 @@ program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 handlers! {
     transfer_sol_with_cpi,
-    transfer_sol_with_program
+    transfer_sol_with_program,
+    transfer_sol_from_vault,
 }
 
 #[context]
@@ -16,6 +17,12 @@ pub struct TransferContext {
     pub recipient: Mut<SystemAccount>,
 }
 
+#[context]
+pub struct VaultContext {
+    pub vault: Mut<SystemAccount>,
+    pub recipient: Mut<SystemAccount>,
+}
+
 #[context]
 pub struct SystemContext {
     pub system: Program<System>,
@@ -26,7 +33,7 @@ pub fn transfer_sol_with_cpi(
     ctx: TransferContext,
     _: SystemContext,
 ) -> Result<(), ProgramError> {
-    ctx.payer.transfer(&ctx.recipient, *amount)?;
+    ctx.payer.transfer(&ctx.recipient, *amount, None)?;
 
     Ok(())
 }
@@ -39,3 +46,20 @@ pub fn transfer_sol_with_program(
 
     Ok(())
 }
+
+pub fn transfer_sol_from_vault(
+    amount: Args<u64>,
+    ctx: VaultContext,
+    _: SystemContext,
+) -> Result<(), ProgramError> {
+    let (vault, bump) = program::find_program_address(&[b"vault"], &crate::ID);
+    require_keys_eq!(*ctx.vault.key(), vault, ProgramError::InvalidSeeds);
+
+    ctx.vault.transfer(
+        &ctx.recipient,
+        *amount,
+        Some(&[program::signer!(b"vault", &[bump])]),
+    )?;
+
+    Ok(())
+}
@@ -3,7 +3,7 @@ use {
     solana_sdk::{
         instruction::{AccountMeta, Instruction},
         native_token::LAMPORTS_PER_SOL,
-        pubkey,
+        pubkey::{self, Pubkey},
         signature::Keypair,
         signer::Signer,
         system_program,
@@ -110,4 +110,35 @@ fn integration_test() {
     let program_acc = svm.get_balance(&program_acc_pk).unwrap_or_default();
     assert!(admin_balance > 8 * LAMPORTS_PER_SOL);
     assert_eq!(program_acc, 0);
+
+    // Transfer from a PDA vault
+
+    let (vault_pk, _) = Pubkey::find_program_address(&[b"vault"], &program_id);
+    svm.airdrop(&vault_pk, LAMPORTS_PER_SOL).unwrap();
+
+    let amount = LAMPORTS_PER_SOL / 2;
+    let amount_bytes = bytemuck::bytes_of(&amount);
+    let mut data = vec![2];
+    data.extend_from_slice(amount_bytes);
+
+    let ix = Instruction {
+        accounts: vec![
+            AccountMeta::new(vault_pk, false),
+            AccountMeta::new(recipient_pk, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        program_id,
+        data,
+    };
+
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+
+    let res = svm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let vault_balance = svm.get_balance(&vault_pk).unwrap_or_default();
+    let recipient_balance = svm.get_balance(&recipient_pk).unwrap_or_default();
+    assert_eq!(vault_balance, LAMPORTS_PER_SOL / 2);
+    assert_eq!(recipient_balance, LAMPORTS_PER_SOL + LAMPORTS_PER_SOL / 2);
 }
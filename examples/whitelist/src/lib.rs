@@ -0,0 +1,82 @@
+use {
+    bytemuck::{Pod, Zeroable},
+    program::pubkey::Pubkey,
+    typhoon::prelude::*,
+};
+
+program_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[context]
+pub struct InitContext {
+    pub payer: Mut<Signer>,
+    #[constraint(
+        init,
+        payer = payer,
+        space = Whitelist::space(0)
+    )]
+    pub whitelist: Mut<Signer<Account<Whitelist>>>,
+    pub system: Program<System>,
+}
+
+#[context]
+pub struct UpdateContext {
+    pub authority: Mut<Signer>,
+    pub whitelist: Mut<Account<Whitelist>>,
+    pub system: Program<System>,
+}
+
+handlers! {
+    initialize,
+    add,
+    remove,
+}
+
+pub fn initialize(ctx: InitContext) -> Result<(), ProgramError> {
+    ctx.whitelist.mut_data()?.authority = *ctx.payer.key();
+
+    Ok(())
+}
+
+/// Adds `key` to the whitelist, the authority paying for the extra space.
+pub fn add(key: Args<Pubkey>, ctx: UpdateContext) -> Result<(), ProgramError> {
+    check_authority(&ctx)?;
+    require!(
+        !ctx.whitelist.data()?.keys.contains(&key),
+        ProgramError::InvalidArgument
+    );
+
+    ctx.whitelist.push(&key, &ctx.authority)
+}
+
+/// Removes `key` from the whitelist, the freed rent going back to the authority.
+pub fn remove(key: Args<Pubkey>, ctx: UpdateContext) -> Result<(), ProgramError> {
+    check_authority(&ctx)?;
+    let index = ctx
+        .whitelist
+        .data()?
+        .keys
+        .iter()
+        .position(|entry| entry == &*key)
+        .ok_or(ProgramError::InvalidArgument)?;
+
+    ctx.whitelist.remove(index, &ctx.authority)?;
+
+    Ok(())
+}
+
+fn check_authority(ctx: &UpdateContext) -> Result<(), ProgramError> {
+    require_keys_eq!(
+        ctx.whitelist.data()?.authority,
+        *ctx.authority.key(),
+        ProgramError::InvalidAccountData
+    );
+
+    Ok(())
+}
+
+#[account]
+pub struct Whitelist {
+    pub authority: Pubkey,
+    #[tail]
+    pub keys: [Pubkey],
+}
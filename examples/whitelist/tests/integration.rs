@@ -0,0 +1,123 @@
+use {
+    litesvm::LiteSVM,
+    solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::{self, Pubkey},
+        signature::Keypair,
+        signer::Signer,
+        system_program,
+        transaction::Transaction,
+    },
+    std::path::PathBuf,
+};
+
+fn read_program() -> Vec<u8> {
+    let mut so_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    so_path.push("../../target/deploy/whitelist.so");
+
+    std::fs::read(so_path).unwrap()
+}
+
+fn update_ix(
+    program_id: Pubkey,
+    authority: Pubkey,
+    whitelist: Pubkey,
+    discriminator: u8,
+    key: Pubkey,
+) -> Instruction {
+    let mut data = vec![discriminator];
+    data.extend_from_slice(key.as_ref());
+
+    Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(authority, true),
+            AccountMeta::new(whitelist, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    }
+}
+
+#[test]
+fn integration_test() {
+    let mut svm = LiteSVM::new();
+    let admin_kp = Keypair::new();
+    let admin_pk = admin_kp.pubkey();
+
+    svm.airdrop(&admin_pk, 10 * LAMPORTS_PER_SOL).unwrap();
+
+    let program_id = pubkey!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+    let program_bytes = read_program();
+
+    svm.add_program(program_id, &program_bytes);
+
+    // Create the whitelist
+    let whitelist_kp = Keypair::new();
+    let whitelist_pk = whitelist_kp.pubkey();
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(admin_pk, true),
+            AccountMeta::new(whitelist_pk, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: vec![0],
+    };
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&admin_pk),
+        &[&admin_kp, &whitelist_kp],
+        hash,
+    );
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&whitelist_pk).unwrap();
+    assert_eq!(account.data, admin_pk.to_bytes());
+
+    // Add two keys
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let ixs = [
+        update_ix(program_id, admin_pk, whitelist_pk, 1, first),
+        update_ix(program_id, admin_pk, whitelist_pk, 1, second),
+    ];
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&admin_pk), &[&admin_kp], hash);
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&whitelist_pk).unwrap();
+    assert_eq!(account.data.len(), 96);
+    assert_eq!(&account.data[32..64], first.as_ref());
+    assert_eq!(&account.data[64..], second.as_ref());
+    assert_eq!(account.lamports, svm.minimum_balance_for_rent_exemption(96));
+
+    // A key can't be added twice
+    let ix = update_ix(program_id, admin_pk, whitelist_pk, 1, first);
+    svm.expire_blockhash();
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+    assert!(svm.send_transaction(tx).is_err());
+
+    // Remove the first key, the rent of its entry is refunded
+    let ix = update_ix(program_id, admin_pk, whitelist_pk, 2, first);
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&admin_pk), &[&admin_kp], hash);
+    svm.send_transaction(tx).unwrap();
+
+    let account = svm.get_account(&whitelist_pk).unwrap();
+    assert_eq!(account.data.len(), 64);
+    assert_eq!(&account.data[32..], second.as_ref());
+    assert_eq!(account.lamports, svm.minimum_balance_for_rent_exemption(64));
+
+    // Only the authority can update the whitelist
+    let other_kp = Keypair::new();
+    let other_pk = other_kp.pubkey();
+    svm.airdrop(&other_pk, LAMPORTS_PER_SOL).unwrap();
+    let ix = update_ix(program_id, other_pk, whitelist_pk, 1, other_pk);
+    let hash = svm.latest_blockhash();
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&other_pk), &[&other_kp], hash);
+    assert!(svm.send_transaction(tx).is_err());
+}